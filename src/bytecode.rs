@@ -0,0 +1,5 @@
+pub mod instruction;
+pub mod compiler;
+pub mod vm;
+pub mod disassembler;
+pub mod assembler;
@@ -0,0 +1,206 @@
+use crate::bytecode::instruction::{Builtin, Chunk, Instruction, BINARY_MNEMONICS};
+use crate::error_handler::{error_code::ErrorCode, error_handler::ErrorHandler};
+use crate::parser::ast::Command;
+use crate::span::Span;
+use crate::symbol_table::symbol_type::SymbolType;
+
+/// Palavra de uma linha de assembly e onde ela está no texto.
+struct Word<'s> {
+    text: &'s str,
+    span: Span,
+}
+
+/// Lê a forma textual escrita pelo `Disassembler` de volta para um `Chunk`.
+///
+/// Cada linha tem uma diretiva `.slot nome` ou uma instrução, opcionalmente
+/// precedida do seu índice; `;` começa um comentário. Os spans do `Chunk`
+/// apontam para as linhas do assembly, então erros de execução mostram a
+/// instrução que falhou.
+///
+/// Além da sintaxe, confere slots, destinos de salto e a profundidade da pilha
+/// em todos os caminhos, para que a `Vm` nunca desempilhe de uma pilha vazia.
+pub struct Assembler<'a> {
+    source: &'a str,
+    errors: ErrorHandler,
+}
+
+impl<'a> Assembler<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Assembler {
+            source,
+            errors: ErrorHandler::new(),
+        }
+    }
+
+    pub fn assemble(mut self) -> (Option<Chunk>, ErrorHandler) {
+        let mut chunk = Chunk::new();
+        let mut offset = 0;
+        for line in self.source.split_inclusive('\n') {
+            let words = self.words(line, offset);
+            offset += line.len();
+            self.assemble_line(&mut chunk, &words);
+        }
+        if !self.errors.has_errors() {
+            for (code, msg, span) in chunk.verify() {
+                self.error(code, &msg, span);
+            }
+        }
+        if self.errors.has_errors() {
+            return (None, self.errors);
+        }
+        (Some(chunk), self.errors)
+    }
+
+    /// Separa a linha em palavras; uma string entre aspas é uma palavra só.
+    fn words<'s>(&mut self, line: &'s str, offset: usize) -> Vec<Word<'s>> {
+        let mut words = Vec::new();
+        let mut chars = line.char_indices().peekable();
+        while let Some(&(start, ch)) = chars.peek() {
+            if ch.is_whitespace() {
+                chars.next();
+                continue;
+            }
+            if ch == ';' {
+                break;
+            }
+            let mut end = line.len();
+            let mut escaped = false;
+            let mut closed = ch != '"';
+            chars.next();
+            while let Some(&(index, next)) = chars.peek() {
+                if ch == '"' {
+                    chars.next();
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == '"' {
+                        end = index + 1;
+                        closed = true;
+                        break;
+                    }
+                } else if next.is_whitespace() {
+                    end = index;
+                    break;
+                } else {
+                    chars.next();
+                }
+            }
+            let span = Span::new(offset + start, offset + end);
+            if !closed {
+                self.error(ErrorCode::InvalidOperand, "Unterminated string", span);
+            }
+            words.push(Word { text: &line[start..end], span });
+        }
+        words
+    }
+
+    fn assemble_line(&mut self, chunk: &mut Chunk, words: &[Word]) {
+        let mut words = words;
+        let Some(first) = words.first() else {
+            return;
+        };
+        if first.text == ".slot" {
+            match words {
+                [_, name] => chunk.slots.push(name.text.to_string()),
+                _ => self.error(ErrorCode::InvalidOperand, "'.slot' expects exactly one name", first.span),
+            }
+            return;
+        }
+        if first.text.starts_with(|ch: char| ch.is_ascii_digit()) {
+            if first.text.parse::<usize>().ok() != Some(chunk.len()) {
+                let msg = format!("Instruction index '{}' does not match its position {}", first.text, chunk.len());
+                self.error(ErrorCode::InvalidOperand, &msg, first.span);
+            }
+            words = &words[1..];
+        }
+        let Some((mnemonic, operands)) = words.split_first() else {
+            return;
+        };
+        if let Some(instruction) = self.parse_instruction(mnemonic, operands) {
+            let end = operands.last().unwrap_or(mnemonic).span.end;
+            chunk.push(instruction, Span::new(mnemonic.span.start, end));
+        }
+    }
+
+    fn parse_instruction(&mut self, mnemonic: &Word, operands: &[Word]) -> Option<Instruction> {
+        let binary = BINARY_MNEMONICS.iter().find(|(_, name)| *name == mnemonic.text);
+        let arity = match mnemonic.text {
+            _ if binary.is_some() => 0,
+            "pop" | "not" | "neg" | "tick" => 0,
+            "push_int" | "push_bool" | "load" | "store" | "load_builtin" | "jump" | "jump_if_false" | "jump_if_true" | "command" => 1,
+            "init" | "fail" => 2,
+            other => {
+                self.error(ErrorCode::UnknownMnemonic, &format!("Unknown instruction '{}'", other), mnemonic.span);
+                return None;
+            }
+        };
+        if operands.len() != arity {
+            let msg = format!("'{}' expects {} operand(s), found {}", mnemonic.text, arity, operands.len());
+            self.error(ErrorCode::InvalidOperand, &msg, mnemonic.span);
+            return None;
+        }
+
+        let instruction = match mnemonic.text {
+            _ if binary.is_some() => Instruction::Binary(binary?.0.clone()),
+            "pop" => Instruction::Pop,
+            "not" => Instruction::Not,
+            "neg" => Instruction::Neg,
+            "tick" => Instruction::Tick,
+            "push_int" => Instruction::PushInt(self.operand(&operands[0], "an integer", |text| text.parse().ok())?),
+            "push_bool" => Instruction::PushBool(self.operand(&operands[0], "'true' or 'false'", |text| text.parse().ok())?),
+            "load" => Instruction::Load(self.slot(&operands[0])?),
+            "store" => Instruction::Store(self.slot(&operands[0])?),
+            "load_builtin" => Instruction::LoadBuiltin(self.operand(&operands[0], "a predefined symbol", Builtin::from_name)?),
+            "jump" => Instruction::Jump(self.target(&operands[0])?),
+            "jump_if_false" => Instruction::JumpIfFalse(self.target(&operands[0])?),
+            "jump_if_true" => Instruction::JumpIfTrue(self.target(&operands[0])?),
+            "command" => Instruction::Command(self.operand(&operands[0], "a command", Command::from_keyword)?),
+            "init" => {
+                let slot = self.slot(&operands[0])?;
+                let var_type = self.operand(&operands[1], "'int' or 'bool'", SymbolType::from_keyword)?;
+                Instruction::Init(slot, var_type)
+            }
+            _ => {
+                let code = self.operand(&operands[0], "an error code", ErrorCode::parse)?;
+                let msg = self.operand(&operands[1], "a quoted message", unquote)?;
+                Instruction::Fail(code, msg)
+            }
+        };
+        Some(instruction)
+    }
+
+    fn operand<T>(&mut self, word: &Word, expected: &str, parse: impl FnOnce(&str) -> Option<T>) -> Option<T> {
+        let parsed = parse(word.text);
+        if parsed.is_none() {
+            self.error(ErrorCode::InvalidOperand, &format!("Expected {}, found '{}'", expected, word.text), word.span);
+        }
+        parsed
+    }
+
+    fn slot(&mut self, word: &Word) -> Option<usize> {
+        self.operand(word, "a slot number", |text| text.parse().ok())
+    }
+
+    fn target(&mut self, word: &Word) -> Option<usize> {
+        self.operand(word, "an instruction index", |text| text.parse().ok())
+    }
+
+    fn error(&mut self, code: ErrorCode, msg: &str, span: Span) {
+        self.errors.add_error_at(code, msg, span, self.source);
+    }
+}
+
+/// Tira as aspas e desfaz os escapes `\"` e `\\`.
+fn unquote(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => out.push(chars.next()?),
+            ch => out.push(ch),
+        }
+    }
+    Some(out)
+}
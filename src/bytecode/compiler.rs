@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use crate::bytecode::instruction::{Builtin, Chunk, Instruction};
+use crate::error_handler::error_code::ErrorCode;
+use crate::parser::ast::{BinOp, Expr, Span, Stmt, UnaryOp};
+
+/// Traduz a AST para bytecode.
+///
+/// Cada `let` ganha um slot próprio e os nomes são resolvidos aqui, seguindo os
+/// mesmos escopos do interpretador. O que o interpretador só descobre ao executar,
+/// como um identificador indefinido, vira uma instrução `Fail` no mesmo ponto.
+pub struct Compiler {
+    chunk: Chunk,
+    scopes: Vec<HashMap<String, usize>>,
+}
+
+impl Default for Compiler {
+    fn default() -> Self {
+        Compiler::new()
+    }
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Compiler {
+            chunk: Chunk::new(),
+            scopes: vec![HashMap::new()],
+        }
+    }
+
+    pub fn compile(mut self, stmts: &[Stmt]) -> Chunk {
+        for stmt in stmts {
+            self.compile_stmt(stmt);
+        }
+        self.chunk
+    }
+
+    fn compile_scoped(&mut self, stmts: &[Stmt]) {
+        self.scopes.push(HashMap::new());
+        for stmt in stmts {
+            self.compile_stmt(stmt);
+        }
+        self.scopes.pop();
+    }
+
+    fn compile_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Command(command, span) => {
+                self.emit(Instruction::Command(command.clone()), *span);
+            }
+            Stmt::IfStmt { condition, then_branch, else_branch, span } => {
+                self.compile_expr(condition);
+                let to_else = self.emit_jump(Instruction::JumpIfFalse, *span);
+                self.compile_scoped(then_branch);
+                if else_branch.is_empty() {
+                    self.patch_jump(to_else);
+                } else {
+                    let to_end = self.emit_jump(Instruction::Jump, *span);
+                    self.patch_jump(to_else);
+                    self.compile_scoped(else_branch);
+                    self.patch_jump(to_end);
+                }
+            }
+            Stmt::WhileStmt { condition, body, span } => {
+                let start = self.chunk.len();
+                self.compile_expr(condition);
+                let to_end = self.emit_jump(Instruction::JumpIfFalse, *span);
+                self.emit(Instruction::Tick, *span);
+                self.compile_scoped(body);
+                self.emit(Instruction::Jump(start), *span);
+                self.patch_jump(to_end);
+            }
+            Stmt::ForStmt { init, condition, update, body, span } => {
+                self.scopes.push(HashMap::new());
+                self.compile_stmt(init);
+                let start = self.chunk.len();
+                self.compile_expr(condition);
+                let to_end = self.emit_jump(Instruction::JumpIfFalse, *span);
+                self.emit(Instruction::Tick, *span);
+                self.compile_scoped(body);
+                self.compile_stmt(update);
+                self.emit(Instruction::Jump(start), *span);
+                self.patch_jump(to_end);
+                self.scopes.pop();
+            }
+            Stmt::LetStmt { name, var_type, value, span, .. } => {
+                self.compile_expr(value);
+                let slot = self.chunk.slots.len();
+                self.chunk.slots.push(name.clone());
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.insert(name.clone(), slot);
+                }
+                self.emit(Instruction::Init(slot, var_type.clone()), *span);
+            }
+            Stmt::AssignStmt { name, value, span, .. } => {
+                self.compile_expr(value);
+                match self.resolve(name) {
+                    Some(slot) => self.emit(Instruction::Store(slot), *span),
+                    None => self.emit(
+                        Instruction::Fail(ErrorCode::UndefinedAtRuntime, format!("Assignment to undeclared variable '{}'", name)),
+                        *span,
+                    ),
+                };
+            }
+            Stmt::ExprStmt(expr) => {
+                self.compile_expr(expr);
+                self.emit(Instruction::Pop, expr.span());
+            }
+            Stmt::Error(span) => {
+                self.emit(unparsed(), *span);
+            }
+        }
+    }
+
+    fn compile_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Identifier(name, span) => {
+                let instruction = match (self.resolve(name), Builtin::from_name(name)) {
+                    (Some(slot), _) => Instruction::Load(slot),
+                    (None, Some(builtin)) => Instruction::LoadBuiltin(builtin),
+                    (None, None) => Instruction::Fail(ErrorCode::UndefinedAtRuntime, format!("Undefined identifier '{}'", name)),
+                };
+                self.emit(instruction, *span);
+            }
+            Expr::Number(n, span) => {
+                self.emit(Instruction::PushInt(*n), *span);
+            }
+            Expr::Error(span) => {
+                self.emit(unparsed(), *span);
+            }
+            // `a && b` e `a || b` avaliam `b` só quando precisam e sempre deixam um booleano.
+            Expr::BinaryOp { left, op: op @ (BinOp::And | BinOp::Or), right, span, .. } => {
+                let short_circuit = match op {
+                    BinOp::And => Instruction::JumpIfFalse,
+                    _ => Instruction::JumpIfTrue,
+                };
+                self.compile_expr(left);
+                let first = self.emit_jump(short_circuit, *span);
+                self.compile_expr(right);
+                let second = self.emit_jump(short_circuit, *span);
+                self.emit(Instruction::PushBool(*op == BinOp::And), *span);
+                let to_end = self.emit_jump(Instruction::Jump, *span);
+                self.patch_jump(first);
+                self.patch_jump(second);
+                self.emit(Instruction::PushBool(*op == BinOp::Or), *span);
+                self.patch_jump(to_end);
+            }
+            Expr::BinaryOp { left, op, right, span, .. } => {
+                self.compile_expr(left);
+                self.compile_expr(right);
+                self.emit(Instruction::Binary(op.clone()), *span);
+            }
+            Expr::UnaryOp { op, operand, span } => {
+                self.compile_expr(operand);
+                let instruction = match op {
+                    UnaryOp::Not => Instruction::Not,
+                    UnaryOp::Neg => Instruction::Neg,
+                };
+                self.emit(instruction, *span);
+            }
+        }
+    }
+
+    fn resolve(&self, name: &str) -> Option<usize> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name).copied())
+    }
+
+    fn emit(&mut self, instruction: Instruction, span: Span) -> usize {
+        self.chunk.push(instruction, span)
+    }
+
+    /// Emite um salto com destino provisório, corrigido depois por `patch_jump`.
+    fn emit_jump(&mut self, jump: fn(usize) -> Instruction, span: Span) -> usize {
+        self.emit(jump(usize::MAX), span)
+    }
+
+    /// Faz o salto em `index` apontar para a próxima instrução emitida.
+    fn patch_jump(&mut self, index: usize) {
+        let target = self.chunk.len();
+        match &mut self.chunk.code[index] {
+            Instruction::Jump(to) | Instruction::JumpIfFalse(to) | Instruction::JumpIfTrue(to) => *to = target,
+            other => unreachable!("instruction {:?} is not a jump", other),
+        }
+    }
+}
+
+fn unparsed() -> Instruction {
+    Instruction::Fail(ErrorCode::UnparsedCode, "Cannot run code that failed to parse".to_string())
+}
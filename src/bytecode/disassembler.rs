@@ -0,0 +1,75 @@
+use crate::bytecode::instruction::{Chunk, Instruction};
+
+/// Coluna em que começam os comentários com nomes de variáveis.
+const COMMENT_COLUMN: usize = 30;
+
+/// Escreve um `Chunk` na forma textual lida pelo `Assembler`:
+///
+/// ```text
+/// .slot steps
+/// ; 1 | let steps: int = 3
+/// 0000  push_int 3
+/// 0001  init 0 int              ; steps
+/// ```
+///
+/// Com o código-fonte, cada grupo de instruções vem depois da linha que o gerou.
+pub struct Disassembler<'a> {
+    chunk: &'a Chunk,
+    source: Option<&'a str>,
+}
+
+impl<'a> Disassembler<'a> {
+    pub fn new(chunk: &'a Chunk) -> Self {
+        Disassembler { chunk, source: None }
+    }
+
+    /// Fonte do programa compilado, usada para anotar as instruções com a linha de origem.
+    pub fn with_source(mut self, source: &'a str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn disassemble(&self) -> String {
+        let mut out = String::new();
+        for name in &self.chunk.slots {
+            out.push_str(&format!(".slot {}\n", name));
+        }
+
+        let lines: Vec<&str> = self.source.map_or_else(Vec::new, |source| source.lines().collect());
+        let line_starts = self.source.map_or_else(Vec::new, line_starts);
+        let mut current_line = None;
+        for (index, instruction) in self.chunk.code.iter().enumerate() {
+            if self.source.is_some() {
+                // Número da linha: quantas linhas começam até a instrução.
+                let line = line_starts.partition_point(|start| *start <= self.chunk.span(index).start);
+                if current_line != Some(line) {
+                    let text = lines.get(line - 1).map_or("", |text| text.trim());
+                    out.push_str(&format!("; {} | {}\n", line, text));
+                    current_line = Some(line);
+                }
+            }
+            let text = format!("{:04}  {}", index, instruction);
+            match self.slot_name(instruction) {
+                Some(name) => out.push_str(&format!("{:<width$}; {}\n", text, name, width = COMMENT_COLUMN)),
+                None => out.push_str(&format!("{}\n", text)),
+            }
+        }
+        out
+    }
+
+    fn slot_name(&self, instruction: &Instruction) -> Option<&str> {
+        match instruction {
+            Instruction::Load(slot) | Instruction::Store(slot) | Instruction::Init(slot, _) => {
+                self.chunk.slots.get(*slot).map(String::as_str)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Offset em que cada linha começa.
+fn line_starts(source: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+        .collect()
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+
+use crate::error_handler::error_code::ErrorCode;
+use crate::parser::ast::{BinOp, Command};
+use crate::span::Span;
+use crate::symbol_table::symbol_type::SymbolType;
+use crate::world::world::PREDEFINED_SYMBOLS;
+
+/// Um dos `PREDEFINED_SYMBOLS`, lido com `World::predefined` durante a execução.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Builtin(&'static str);
+
+impl Builtin {
+    pub fn from_name(name: &str) -> Option<Builtin> {
+        PREDEFINED_SYMBOLS
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(builtin, _)| Builtin(builtin))
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// Instruções da máquina de pilha. Saltos usam o índice absoluto da instrução de destino.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    PushInt(i32),
+    PushBool(bool),
+    /// Descarta o topo da pilha.
+    Pop,
+    /// Empilha a variável local do slot.
+    Load(usize),
+    LoadBuiltin(Builtin),
+    /// Desempilha e inicializa o slot, conferindo o tipo declarado no `let`.
+    Init(usize, SymbolType),
+    /// Desempilha e atribui ao slot, conferindo o tipo do valor atual.
+    Store(usize),
+    /// Operadores binários sem curto-circuito; `&&` e `||` viram saltos.
+    Binary(BinOp),
+    Not,
+    Neg,
+    Jump(usize),
+    /// Desempilha e salta se o valor for falso.
+    JumpIfFalse(usize),
+    /// Desempilha e salta se o valor for verdadeiro.
+    JumpIfTrue(usize),
+    /// Conta uma iteração de laço no limite de passos.
+    Tick,
+    Command(Command),
+    /// Erro de execução decidido na compilação, como um identificador indefinido.
+    Fail(ErrorCode, String),
+}
+
+/// Nome de cada operador binário na forma textual do bytecode.
+pub const BINARY_MNEMONICS: [(BinOp, &str); 12] = [
+    (BinOp::Add, "add"),
+    (BinOp::Sub, "sub"),
+    (BinOp::Mul, "mul"),
+    (BinOp::Div, "div"),
+    (BinOp::Lt, "lt"),
+    (BinOp::Gt, "gt"),
+    (BinOp::Le, "le"),
+    (BinOp::Ge, "ge"),
+    (BinOp::Eq, "eq"),
+    (BinOp::Ne, "ne"),
+    (BinOp::And, "and"),
+    (BinOp::Or, "or"),
+];
+
+impl Instruction {
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::PushInt(_) => "push_int",
+            Instruction::PushBool(_) => "push_bool",
+            Instruction::Pop => "pop",
+            Instruction::Load(_) => "load",
+            Instruction::LoadBuiltin(_) => "load_builtin",
+            Instruction::Init(..) => "init",
+            Instruction::Store(_) => "store",
+            Instruction::Binary(op) => BINARY_MNEMONICS
+                .iter()
+                .find(|(binary, _)| binary == op)
+                .map_or("?", |(_, mnemonic)| mnemonic),
+            Instruction::Not => "not",
+            Instruction::Neg => "neg",
+            Instruction::Jump(_) => "jump",
+            Instruction::JumpIfFalse(_) => "jump_if_false",
+            Instruction::JumpIfTrue(_) => "jump_if_true",
+            Instruction::Tick => "tick",
+            Instruction::Command(_) => "command",
+            Instruction::Fail(..) => "fail",
+        }
+    }
+
+    /// Quantos valores a instrução desempilha e quantos empilha.
+    pub fn stack_effect(&self) -> (usize, usize) {
+        match self {
+            Instruction::PushInt(_) | Instruction::PushBool(_) | Instruction::Load(_) | Instruction::LoadBuiltin(_) => (0, 1),
+            Instruction::Pop | Instruction::Init(..) | Instruction::Store(_) => (1, 0),
+            Instruction::JumpIfFalse(_) | Instruction::JumpIfTrue(_) => (1, 0),
+            Instruction::Binary(_) => (2, 1),
+            Instruction::Not | Instruction::Neg => (1, 1),
+            Instruction::Jump(_) | Instruction::Tick | Instruction::Command(_) | Instruction::Fail(..) => (0, 0),
+        }
+    }
+}
+
+/// Forma textual lida de volta pelo `Assembler`, como `push_int 3` ou `init 0 int`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        match self {
+            Instruction::PushInt(n) => write!(f, " {}", n),
+            Instruction::PushBool(b) => write!(f, " {}", b),
+            Instruction::Load(slot) | Instruction::Store(slot) => write!(f, " {}", slot),
+            Instruction::LoadBuiltin(builtin) => write!(f, " {}", builtin.name()),
+            Instruction::Init(slot, var_type) => write!(f, " {} {}", slot, var_type.keyword()),
+            Instruction::Jump(target) | Instruction::JumpIfFalse(target) | Instruction::JumpIfTrue(target) => {
+                write!(f, " {}", target)
+            }
+            Instruction::Command(command) => write!(f, " {}", command),
+            Instruction::Fail(code, msg) => write!(f, " {} \"{}\"", code, msg.replace('\\', "\\\\").replace('"', "\\\"")),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Programa compilado: instruções, o span de origem de cada uma e os nomes das variáveis locais.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Chunk {
+    pub code: Vec<Instruction>,
+    pub spans: Vec<Span>,
+    pub slots: Vec<String>,
+}
+
+impl Chunk {
+    pub fn new() -> Self {
+        Chunk::default()
+    }
+
+    /// Acrescenta uma instrução e devolve o seu índice.
+    pub fn push(&mut self, instruction: Instruction, span: Span) -> usize {
+        self.code.push(instruction);
+        self.spans.push(span);
+        self.code.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+
+    /// Span da instrução; um `Chunk` montado à mão pode ter menos spans que instruções.
+    pub fn span(&self, index: usize) -> Span {
+        self.spans.get(index).copied().unwrap_or_default()
+    }
+
+    /// Confere slots, destinos de salto e a profundidade da pilha em todos os caminhos.
+    /// Sem problemas, a `Vm` nunca desempilha de uma pilha vazia nem usa um slot que não existe.
+    pub fn verify(&self) -> Vec<(ErrorCode, String, Span)> {
+        let problems = self.check_operands();
+        if !problems.is_empty() {
+            return problems;
+        }
+        self.check_stack().into_iter().collect()
+    }
+
+    /// Slots declarados e saltos dentro do programa.
+    fn check_operands(&self) -> Vec<(ErrorCode, String, Span)> {
+        let mut problems = Vec::new();
+        for (pc, instruction) in self.code.iter().enumerate() {
+            match instruction {
+                Instruction::Load(slot) | Instruction::Store(slot) | Instruction::Init(slot, _) if *slot >= self.slots.len() => {
+                    let msg = format!("Slot {} is not declared ({} slot(s) declared)", slot, self.slots.len());
+                    problems.push((ErrorCode::UnknownSlot, msg, self.span(pc)));
+                }
+                Instruction::Jump(target) | Instruction::JumpIfFalse(target) | Instruction::JumpIfTrue(target)
+                    if *target > self.len() =>
+                {
+                    let msg = format!("Jump target {} is past the end of the program ({} instructions)", target, self.len());
+                    problems.push((ErrorCode::JumpOutOfRange, msg, self.span(pc)));
+                }
+                _ => {}
+            }
+        }
+        problems
+    }
+
+    /// Percorre todos os caminhos a partir da primeira instrução e exige que cada
+    /// instrução alcançável tenha sempre a mesma profundidade de pilha.
+    fn check_stack(&self) -> Option<(ErrorCode, String, Span)> {
+        let mut depths: Vec<Option<usize>> = vec![None; self.len()];
+        let mut pending = vec![(0, 0)];
+        while let Some((pc, depth)) = pending.pop() {
+            let Some(instruction) = self.code.get(pc) else {
+                continue;
+            };
+            match depths[pc] {
+                Some(known) if known == depth => continue,
+                Some(known) => {
+                    let msg = format!("Instruction {} is reached with stack depths {} and {}", pc, known, depth);
+                    return Some((ErrorCode::StackImbalance, msg, self.span(pc)));
+                }
+                None => depths[pc] = Some(depth),
+            }
+            let (pops, pushes) = instruction.stack_effect();
+            if depth < pops {
+                let msg = format!("'{}' pops {} value(s) but the stack has {}", instruction.mnemonic(), pops, depth);
+                return Some((ErrorCode::StackImbalance, msg, self.span(pc)));
+            }
+            let depth = depth - pops + pushes;
+            match instruction {
+                Instruction::Jump(target) => pending.push((*target, depth)),
+                Instruction::JumpIfFalse(target) | Instruction::JumpIfTrue(target) => {
+                    pending.push((*target, depth));
+                    pending.push((pc + 1, depth));
+                }
+                Instruction::Fail(..) => {}
+                _ => pending.push((pc + 1, depth)),
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,165 @@
+use crate::bytecode::instruction::{Chunk, Instruction};
+use crate::error_handler::{error_code::ErrorCode, error_handler::ErrorHandler};
+use crate::interpreter::interpreter::{eval_binop, negate, Execution, Halt};
+use crate::interpreter::trace::Trace;
+use crate::interpreter::value::Value;
+use crate::parser::ast::{BinOp, Span};
+use crate::world::world::World;
+
+/// Máquina de pilha que executa um `Chunk`.
+///
+/// Produz o mesmo `Trace` e os mesmos erros que o `Interpreter` para a AST
+/// que originou o bytecode, inclusive o limite de passos.
+pub struct Vm<'a> {
+    execution: Execution,
+    source: Option<&'a str>,
+    stack: Vec<Value>,
+    slots: Vec<Option<Value>>,
+    errors: ErrorHandler,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(world: World) -> Self {
+        Vm {
+            execution: Execution::new(world),
+            source: None,
+            stack: Vec::new(),
+            slots: Vec::new(),
+            errors: ErrorHandler::new(),
+        }
+    }
+
+    /// Limita comandos e iterações de laço, para que laços infinitos terminem.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.execution.max_steps = max_steps;
+        self
+    }
+
+    /// Fonte do programa, usada para dar linha e coluna aos erros de execução.
+    pub fn with_source(mut self, source: &'a str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Um `Chunk` que não passa em `Chunk::verify` não é executado; os problemas viram erros.
+    pub fn run(mut self, chunk: &Chunk) -> (Trace, ErrorHandler) {
+        self.slots = vec![None; chunk.slots.len()];
+        let problems = chunk.verify();
+        if problems.is_empty() {
+            if let Err(Halt::Error(code, msg, span)) = self.execute(chunk) {
+                self.error(code, &msg, span);
+            }
+        }
+        for (code, msg, span) in problems {
+            self.error(code, &msg, span);
+        }
+        (self.execution.into_trace(), self.errors)
+    }
+
+    fn execute(&mut self, chunk: &Chunk) -> Result<(), Halt> {
+        let mut pc = 0;
+        while let Some(instruction) = chunk.code.get(pc) {
+            let span = chunk.span(pc);
+            pc += 1;
+            match instruction {
+                Instruction::PushInt(n) => self.stack.push(Value::Int(*n)),
+                Instruction::PushBool(b) => self.stack.push(Value::Bool(*b)),
+                Instruction::Pop => {
+                    self.pop();
+                }
+                Instruction::Load(slot) => {
+                    let value = self.slots[*slot].ok_or_else(|| uninitialized(chunk, *slot, span))?;
+                    self.stack.push(value);
+                }
+                Instruction::LoadBuiltin(builtin) => {
+                    let value = self.execution.world.predefined(builtin.name()).ok_or_else(|| {
+                        Halt::Error(ErrorCode::UndefinedAtRuntime, format!("Undefined identifier '{}'", builtin.name()), span)
+                    })?;
+                    self.stack.push(value);
+                }
+                Instruction::Init(slot, var_type) => {
+                    let value = self.pop();
+                    if value.symbol_type() != *var_type {
+                        return Err(Halt::Error(
+                            ErrorCode::RuntimeTypeMismatch,
+                            format!("Cannot initialize '{}' of type {:?} with {}", chunk.slots[*slot], var_type, value),
+                            span,
+                        ));
+                    }
+                    self.slots[*slot] = Some(value);
+                }
+                Instruction::Store(slot) => {
+                    let value = self.pop();
+                    let current = self.slots[*slot].ok_or_else(|| uninitialized(chunk, *slot, span))?;
+                    if current.symbol_type() != value.symbol_type() {
+                        return Err(Halt::Error(
+                            ErrorCode::RuntimeTypeMismatch,
+                            format!("Cannot assign {} to '{}' of type {:?}", value, chunk.slots[*slot], current.symbol_type()),
+                            span,
+                        ));
+                    }
+                    self.slots[*slot] = Some(value);
+                }
+                // O compilador transforma `&&` e `||` em saltos; em bytecode escrito à mão
+                // eles avaliam os dois lados, com a mesma regra de verdade das condições.
+                Instruction::Binary(op @ (BinOp::And | BinOp::Or)) => {
+                    let right = self.pop().is_truthy();
+                    let left = self.pop().is_truthy();
+                    let value = if *op == BinOp::And { left && right } else { left || right };
+                    self.stack.push(Value::Bool(value));
+                }
+                Instruction::Binary(op) => {
+                    let right = self.pop();
+                    let left = self.pop();
+                    let value = eval_binop(left, op, right, span)?;
+                    self.stack.push(value);
+                }
+                Instruction::Not => {
+                    let value = self.pop();
+                    self.stack.push(Value::Bool(!value.is_truthy()));
+                }
+                Instruction::Neg => {
+                    let value = negate(self.pop(), span)?;
+                    self.stack.push(value);
+                }
+                Instruction::Jump(target) => pc = *target,
+                Instruction::JumpIfFalse(target) => {
+                    if !self.pop().is_truthy() {
+                        pc = *target;
+                    }
+                }
+                Instruction::JumpIfTrue(target) => {
+                    if self.pop().is_truthy() {
+                        pc = *target;
+                    }
+                }
+                Instruction::Tick => self.execution.tick(span)?,
+                Instruction::Command(command) => self.execution.command(command, span)?,
+                Instruction::Fail(code, msg) => return Err(Halt::Error(*code, msg.clone(), span)),
+            }
+        }
+        Ok(())
+    }
+
+    /// `Chunk::verify` garante que há o que desempilhar.
+    fn pop(&mut self) -> Value {
+        self.stack.pop().expect("verified bytecode never pops from an empty stack")
+    }
+
+    fn error(&mut self, code: ErrorCode, msg: &str, span: Span) {
+        match self.source {
+            Some(source) => self.errors.add_error_at(code, msg, span, source),
+            None => self.errors.add_coded_error(code, msg, 0, 0),
+        }
+    }
+}
+
+/// Só acontece em bytecode escrito à mão: o compilador só lê slots depois do `let`.
+fn uninitialized(chunk: &Chunk, slot: usize, span: Span) -> Halt {
+    let name = chunk.slots.get(slot).map_or("?", String::as_str);
+    Halt::Error(
+        ErrorCode::UndefinedAtRuntime,
+        format!("Variable '{}' used before initialization", name),
+        span,
+    )
+}
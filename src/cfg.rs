@@ -0,0 +1,3 @@
+pub mod cfg;
+pub mod dominators;
+pub mod reachability;
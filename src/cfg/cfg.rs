@@ -0,0 +1,162 @@
+use crate::parser::ast::{Expr, Stmt};
+
+pub type BlockId = usize;
+
+/// Como o bloco termina.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terminator {
+    Goto(BlockId),
+    /// Desvio de `if`, `while` ou `for`: segue `then_block` quando a condição é verdadeira.
+    Branch {
+        condition: Expr,
+        then_block: BlockId,
+        else_block: BlockId,
+    },
+    /// Fim do programa; só o bloco de saída termina assim.
+    Exit,
+}
+
+/// Sequência de statements sem desvios, executada sempre do início ao fim.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicBlock {
+    pub stmts: Vec<Stmt>,
+    pub terminator: Terminator,
+}
+
+impl BasicBlock {
+    pub fn successors(&self) -> Vec<BlockId> {
+        match &self.terminator {
+            Terminator::Goto(target) => vec![*target],
+            Terminator::Branch { then_block, else_block, .. } => vec![*then_block, *else_block],
+            Terminator::Exit => vec![],
+        }
+    }
+}
+
+/// Grafo de fluxo de controle de um programa.
+///
+/// `if`, `while` e `for` viram desvios entre blocos básicos; os demais statements
+/// ficam dentro dos blocos, na ordem de execução. O `init` de um `for` vai para o
+/// bloco anterior ao laço e o `update`, para o fim do corpo. Os blocos são numerados
+/// na ordem do código, começando pela entrada.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cfg {
+    pub blocks: Vec<BasicBlock>,
+    pub entry: BlockId,
+    pub exit: BlockId,
+}
+
+impl Cfg {
+    pub fn build(stmts: &[Stmt]) -> Cfg {
+        let mut builder = CfgBuilder {
+            blocks: vec![BasicBlock {
+                stmts: Vec::new(),
+                terminator: Terminator::Exit,
+            }],
+            current: 0,
+        };
+        builder.lower_all(stmts);
+        Cfg {
+            blocks: builder.blocks,
+            entry: 0,
+            exit: builder.current,
+        }
+    }
+
+    pub fn successors(&self, block: BlockId) -> Vec<BlockId> {
+        self.blocks[block].successors()
+    }
+
+    /// Blocos que desviam para cada bloco, na ordem dos blocos de origem.
+    pub fn predecessors(&self) -> Vec<Vec<BlockId>> {
+        let mut predecessors = vec![Vec::new(); self.blocks.len()];
+        for (id, block) in self.blocks.iter().enumerate() {
+            for successor in block.successors() {
+                predecessors[successor].push(id);
+            }
+        }
+        predecessors
+    }
+}
+
+struct CfgBuilder {
+    blocks: Vec<BasicBlock>,
+    /// Bloco que recebe os próximos statements.
+    current: BlockId,
+}
+
+impl CfgBuilder {
+    fn lower_all(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            self.lower(stmt);
+        }
+    }
+
+    fn lower(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                let branch = self.current;
+                let then_block = self.new_block();
+                self.lower_all(then_branch);
+                let then_end = self.current;
+                let else_block = if else_branch.is_empty() {
+                    None
+                } else {
+                    let else_block = self.new_block();
+                    self.lower_all(else_branch);
+                    Some((else_block, self.current))
+                };
+                let join = self.new_block();
+                self.terminate(branch, Terminator::Branch {
+                    condition: condition.clone(),
+                    then_block,
+                    else_block: else_block.map_or(join, |(start, _)| start),
+                });
+                self.terminate(then_end, Terminator::Goto(join));
+                if let Some((_, else_end)) = else_block {
+                    self.terminate(else_end, Terminator::Goto(join));
+                }
+            }
+            Stmt::WhileStmt { condition, body, .. } => self.lower_loop(condition, body, None),
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                self.lower(init);
+                self.lower_loop(condition, body, Some(update));
+            }
+            _ => self.blocks[self.current].stmts.push(stmt.clone()),
+        }
+    }
+
+    /// O cabeçalho só avalia a condição; o fim do corpo volta para ele.
+    fn lower_loop(&mut self, condition: &Expr, body: &[Stmt], update: Option<&Stmt>) {
+        let before = self.current;
+        let header = self.new_block();
+        self.terminate(before, Terminator::Goto(header));
+        let body_block = self.new_block();
+        self.lower_all(body);
+        if let Some(update) = update {
+            self.lower(update);
+        }
+        let body_end = self.current;
+        let after = self.new_block();
+        self.terminate(header, Terminator::Branch {
+            condition: condition.clone(),
+            then_block: body_block,
+            else_block: after,
+        });
+        self.terminate(body_end, Terminator::Goto(header));
+    }
+
+    /// Cria um bloco vazio e passa a escrever nele.
+    fn new_block(&mut self) -> BlockId {
+        self.blocks.push(BasicBlock {
+            stmts: Vec::new(),
+            terminator: Terminator::Exit,
+        });
+        self.current = self.blocks.len() - 1;
+        self.current
+    }
+
+    fn terminate(&mut self, block: BlockId, terminator: Terminator) {
+        self.blocks[block].terminator = terminator;
+    }
+}
@@ -0,0 +1,111 @@
+use crate::cfg::cfg::{BlockId, Cfg};
+
+/// Árvore de dominadores: `a` domina `b` quando todo caminho da entrada até `b` passa por `a`.
+///
+/// Calculada pelo algoritmo iterativo de Cooper, Harvey e Kennedy sobre todas as
+/// arestas do grafo, inclusive as de condições constantes.
+pub struct Dominators {
+    /// Dominador imediato de cada bloco; a entrada aponta para si mesma e
+    /// blocos inalcançáveis ficam sem dominador.
+    idom: Vec<Option<BlockId>>,
+}
+
+impl Dominators {
+    pub fn compute(cfg: &Cfg) -> Self {
+        let order = reverse_postorder(cfg);
+        let mut position = vec![usize::MAX; cfg.blocks.len()];
+        for (index, block) in order.iter().enumerate() {
+            position[*block] = index;
+        }
+        let predecessors = cfg.predecessors();
+
+        let mut idom = vec![None; cfg.blocks.len()];
+        idom[cfg.entry] = Some(cfg.entry);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &block in order.iter().skip(1) {
+                let mut processed = predecessors[block].iter().copied().filter(|pred| idom[*pred].is_some());
+                let Some(first) = processed.next() else {
+                    continue;
+                };
+                let new_idom = processed.fold(first, |a, b| intersect(&idom, &position, a, b));
+                if idom[block] != Some(new_idom) {
+                    idom[block] = Some(new_idom);
+                    changed = true;
+                }
+            }
+        }
+        Dominators { idom }
+    }
+
+    /// `None` para a entrada e para blocos inalcançáveis.
+    pub fn immediate_dominator(&self, block: BlockId) -> Option<BlockId> {
+        self.idom[block].filter(|idom| *idom != block)
+    }
+
+    /// Todo bloco alcançável domina a si mesmo.
+    pub fn dominates(&self, a: BlockId, b: BlockId) -> bool {
+        self.dominators_of(b).contains(&a)
+    }
+
+    /// Dominadores de `block`, do próprio bloco até a entrada.
+    pub fn dominators_of(&self, block: BlockId) -> Vec<BlockId> {
+        if self.idom[block].is_none() {
+            return Vec::new();
+        }
+        let mut chain = vec![block];
+        let mut current = block;
+        while let Some(idom) = self.immediate_dominator(current) {
+            chain.push(idom);
+            current = idom;
+        }
+        chain
+    }
+
+    /// Arestas que voltam para um bloco que domina a origem: o fim do corpo de um laço
+    /// voltando ao cabeçalho.
+    pub fn back_edges(&self, cfg: &Cfg) -> Vec<(BlockId, BlockId)> {
+        (0..cfg.blocks.len())
+            .flat_map(|from| cfg.successors(from).into_iter().map(move |to| (from, to)))
+            .filter(|(from, to)| self.dominates(*to, *from))
+            .collect()
+    }
+}
+
+/// Sobe pelos dominadores dos dois blocos até o ancestral comum.
+fn intersect(idom: &[Option<BlockId>], position: &[usize], mut a: BlockId, mut b: BlockId) -> BlockId {
+    while a != b {
+        while position[a] > position[b] {
+            a = idom[a].expect("processed blocks have a dominator");
+        }
+        while position[b] > position[a] {
+            b = idom[b].expect("processed blocks have a dominator");
+        }
+    }
+    a
+}
+
+/// Blocos alcançáveis a partir da entrada, em pós-ordem reversa.
+fn reverse_postorder(cfg: &Cfg) -> Vec<BlockId> {
+    let mut visited = vec![false; cfg.blocks.len()];
+    let mut postorder = Vec::new();
+    // Pilha explícita: (bloco, próximo sucessor a visitar).
+    let mut stack = vec![(cfg.entry, 0)];
+    visited[cfg.entry] = true;
+    while let Some((block, next)) = stack.pop() {
+        let successors = cfg.successors(block);
+        match successors.get(next) {
+            Some(&successor) => {
+                stack.push((block, next + 1));
+                if !visited[successor] {
+                    visited[successor] = true;
+                    stack.push((successor, 0));
+                }
+            }
+            None => postorder.push(block),
+        }
+    }
+    postorder.reverse();
+    postorder
+}
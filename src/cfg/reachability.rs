@@ -0,0 +1,77 @@
+use crate::cfg::cfg::{BasicBlock, BlockId, Cfg, Terminator};
+use crate::interpreter::interpreter::{eval_binop, negate};
+use crate::interpreter::value::Value;
+use crate::parser::ast::{BinOp, Expr, UnaryOp};
+
+/// Blocos que podem ser executados a partir da entrada.
+///
+/// Um desvio cuja condição só depende de números só segue a aresta que a condição
+/// escolhe, então o corpo de `while (0)` e o `then` de `if (2 > 3)` ficam inalcançáveis.
+pub struct Reachability {
+    reachable: Vec<bool>,
+}
+
+impl Reachability {
+    pub fn compute(cfg: &Cfg) -> Self {
+        let mut reachable = vec![false; cfg.blocks.len()];
+        let mut pending = vec![cfg.entry];
+        while let Some(block) = pending.pop() {
+            if reachable[block] {
+                continue;
+            }
+            reachable[block] = true;
+            pending.extend(feasible_successors(&cfg.blocks[block]));
+        }
+        Reachability { reachable }
+    }
+
+    pub fn is_reachable(&self, block: BlockId) -> bool {
+        self.reachable[block]
+    }
+
+    /// Blocos inalcançáveis que têm statements, ou seja, código que nunca roda.
+    pub fn unreachable_code(&self, cfg: &Cfg) -> Vec<BlockId> {
+        (0..cfg.blocks.len())
+            .filter(|block| !self.reachable[*block] && !cfg.blocks[*block].stmts.is_empty())
+            .collect()
+    }
+}
+
+/// Sucessores que a execução pode de fato seguir.
+pub fn feasible_successors(block: &BasicBlock) -> Vec<BlockId> {
+    match &block.terminator {
+        Terminator::Branch { condition, then_block, else_block } => match constant_condition(condition) {
+            Some(true) => vec![*then_block],
+            Some(false) => vec![*else_block],
+            None => vec![*then_block, *else_block],
+        },
+        _ => block.successors(),
+    }
+}
+
+/// Valor de uma condição conhecido sem executar o programa, com a regra de verdade do interpretador.
+pub fn constant_condition(condition: &Expr) -> Option<bool> {
+    constant_value(condition).map(|value| value.is_truthy())
+}
+
+/// Valor de uma expressão só com números, calculado como o interpretador calcularia.
+/// `None` quando depende de variáveis ou a conta falharia ao executar.
+///
+/// Como no interpretador, `&&` e `||` param no operando esquerdo quando ele já decide
+/// o resultado: `0 && hero` é sempre falso.
+pub fn constant_value(expr: &Expr) -> Option<Value> {
+    match expr {
+        Expr::Number(n, _) => Some(Value::Int(*n)),
+        Expr::Identifier(..) | Expr::Error(_) => None,
+        Expr::BinaryOp { left, op: op @ (BinOp::And | BinOp::Or), right, .. } => {
+            let left = constant_value(left)?.is_truthy();
+            if left == (*op == BinOp::Or) {
+                return Some(Value::Bool(left));
+            }
+            Some(Value::Bool(constant_value(right)?.is_truthy()))
+        }
+        Expr::BinaryOp { left, op, right, span, .. } => eval_binop(constant_value(left)?, op, constant_value(right)?, *span).ok(),
+        Expr::UnaryOp { op: UnaryOp::Not, operand, .. } => Some(Value::Bool(!constant_value(operand)?.is_truthy())),
+        Expr::UnaryOp { op: UnaryOp::Neg, operand, span } => negate(constant_value(operand)?, *span).ok(),
+    }
+}
@@ -0,0 +1,181 @@
+/// Texto de ajuda do `questlang-compiler`.
+pub const USAGE: &str = "\
+Uso: questlang-compiler <comando> [opções] <arquivo_fonte> [arquivo_mapa]
+     questlang-compiler --explain <código>
+
+Comandos:
+  lex     mostra os tokens do arquivo
+  parse   mostra a AST do arquivo
+  check   roda as análises sem executar o programa
+  fmt     mostra o programa formatado (com --check, só verifica)
+  disasm  mostra o bytecode compilado, anotado com as linhas do fonte
+  run     executa o programa no mapa (padrão quando o comando é omitido)
+
+Opções:
+  --error-format=human|json  formato dos diagnósticos (sempre na saída de erro)
+  --emit=ast|ast-json|ast-dot|cfg-dot
+                             com parse, mostra a AST legível (padrão), em JSON,
+                             ou a AST e o grafo de fluxo de controle para o Graphviz
+  --check                    com fmt, falha se o arquivo não estiver formatado
+  --vm                       com run, executa o programa compilado para bytecode
+  --optimize                 com parse, check, disasm e run, simplifica contas e
+                             desvios constantes e avisa cada mudança
+  -q, --quiet                mostra só os diagnósticos
+  -v, --verbose              mostra também a tabela de símbolos e os tempos de cada fase
+  -h, --help                 mostra esta ajuda
+
+Código de saída: 0 sem erros, 1 com erros no programa ou arquivo não formatado, 2 com erro de uso ou de leitura.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Lex,
+    Parse,
+    Check,
+    Fmt,
+    Disasm,
+    Run,
+}
+
+impl Command {
+    fn from_name(name: &str) -> Option<Command> {
+        match name {
+            "lex" => Some(Command::Lex),
+            "parse" => Some(Command::Parse),
+            "check" => Some(Command::Check),
+            "fmt" => Some(Command::Fmt),
+            "disasm" => Some(Command::Disasm),
+            "run" => Some(Command::Run),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    Human,
+    Json,
+}
+
+/// O que `parse` imprime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emit {
+    Ast,
+    AstJson,
+    AstDot,
+    CfgDot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub command: Command,
+    pub source: String,
+    pub map: Option<String>,
+    pub error_format: ErrorFormat,
+    pub verbosity: Verbosity,
+    pub emit: Emit,
+    /// `fmt --check`: compara em vez de imprimir.
+    pub check: bool,
+    /// `run --vm`: executa na máquina de bytecode em vez de percorrer a AST.
+    pub vm: bool,
+    /// Passa a AST pelo `Optimizer` antes de imprimir, compilar ou executar.
+    pub optimize: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Invocation {
+    Compile(Options),
+    Explain(String),
+    Help,
+}
+
+/// Interpreta os argumentos, sem o nome do programa.
+/// Sem subcomando, `questlang-compiler prog.quest mapa.txt` continua equivalendo a `run`.
+pub fn parse_args(args: &[String]) -> Result<Invocation, String> {
+    let mut command = None;
+    let mut positional = Vec::new();
+    let mut error_format = ErrorFormat::Human;
+    let mut verbosity = Verbosity::Normal;
+    let mut emit = None;
+    let mut check = false;
+    let mut vm = false;
+    let mut optimize = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Invocation::Help),
+            "--explain" => {
+                let code = args.next().ok_or("--explain precisa de um código de erro")?;
+                return Ok(Invocation::Explain(code.clone()));
+            }
+            "-q" | "--quiet" => verbosity = Verbosity::Quiet,
+            "-v" | "--verbose" => verbosity = Verbosity::Verbose,
+            "--check" => check = true,
+            "--vm" => vm = true,
+            "--optimize" => optimize = true,
+            flag if flag.starts_with("--error-format=") => {
+                error_format = match flag.trim_start_matches("--error-format=") {
+                    "human" => ErrorFormat::Human,
+                    "json" => ErrorFormat::Json,
+                    other => return Err(format!("Formato de erro desconhecido: {} (use 'human' ou 'json')", other)),
+                };
+            }
+            flag if flag.starts_with("--emit=") => {
+                emit = match flag.trim_start_matches("--emit=") {
+                    "ast" => Some(Emit::Ast),
+                    "ast-json" => Some(Emit::AstJson),
+                    "ast-dot" => Some(Emit::AstDot),
+                    "cfg-dot" => Some(Emit::CfgDot),
+                    other => return Err(format!("Formato de saída desconhecido: {} (use 'ast', 'ast-json', 'ast-dot' ou 'cfg-dot')", other)),
+                };
+            }
+            flag if flag.starts_with('-') => return Err(format!("Opção desconhecida: {}", flag)),
+            name if command.is_none() && positional.is_empty() && Command::from_name(name).is_some() => {
+                command = Command::from_name(name);
+            }
+            path => positional.push(path.to_string()),
+        }
+    }
+
+    let command = command.unwrap_or(Command::Run);
+    let mut positional = positional.into_iter();
+    let source = positional.next().ok_or("Nenhum arquivo fonte informado")?;
+    let map = positional.next();
+    if map.is_some() && command != Command::Run {
+        return Err("Só o comando 'run' recebe um arquivo de mapa".to_string());
+    }
+    if check && command != Command::Fmt {
+        return Err("--check só vale para o comando 'fmt'".to_string());
+    }
+    if vm && command != Command::Run {
+        return Err("--vm só vale para o comando 'run'".to_string());
+    }
+    if optimize && matches!(command, Command::Lex | Command::Fmt) {
+        return Err("--optimize não vale para os comandos 'lex' e 'fmt'".to_string());
+    }
+    if emit.is_some() && command != Command::Parse {
+        return Err("--emit só vale para o comando 'parse'".to_string());
+    }
+    if let Some(extra) = positional.next() {
+        return Err(format!("Argumento inesperado: {}", extra));
+    }
+
+    Ok(Invocation::Compile(Options {
+        command,
+        source,
+        map,
+        error_format,
+        verbosity,
+        emit: emit.unwrap_or(Emit::Ast),
+        check,
+        vm,
+        optimize,
+    }))
+}
@@ -0,0 +1,2 @@
+pub mod trivia;
+pub mod cst;
@@ -0,0 +1,183 @@
+use crate::cst::trivia::{lex_with_trivia, SyntaxToken, Trivia};
+use crate::error_handler::error_handler::ErrorHandler;
+use crate::lexer::lexer::Lexer;
+use crate::lexer::token::Token;
+use crate::parser::ast::{Expr, Stmt};
+use crate::parser::parser::Parser;
+use crate::span::Span;
+use std::iter::Peekable;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxKind {
+    Program,
+    Command,
+    If,
+    While,
+    For,
+    Let,
+    Assign,
+    ExprStmt,
+    ErrorStmt,
+    Identifier,
+    Number,
+    BinaryOp,
+    UnaryOp,
+    ErrorExpr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken),
+}
+
+/// Nó da árvore concreta: cobre o mesmo trecho do nó correspondente da AST,
+/// mas guarda todos os tokens, inclusive `\n`, parênteses e a trivia.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxNode {
+    pub kind: SyntaxKind,
+    pub span: Span,
+    pub children: Vec<SyntaxElement>,
+}
+
+impl SyntaxNode {
+    /// Texto do nó, com a trivia dos seus tokens.
+    pub fn text(&self) -> String {
+        self.tokens().iter().map(|token| token.full_text()).collect()
+    }
+
+    /// Todos os tokens do nó, em ordem.
+    pub fn tokens(&self) -> Vec<&SyntaxToken> {
+        let mut tokens = Vec::new();
+        for child in &self.children {
+            match child {
+                SyntaxElement::Node(node) => tokens.extend(node.tokens()),
+                SyntaxElement::Token(token) => tokens.push(token),
+            }
+        }
+        tokens
+    }
+
+    /// Nós filhos diretos.
+    pub fn nodes(&self) -> impl Iterator<Item = &SyntaxNode> {
+        self.children.iter().filter_map(|child| match child {
+            SyntaxElement::Node(node) => Some(node),
+            SyntaxElement::Token(_) => None,
+        })
+    }
+
+    pub fn comments(&self) -> Vec<&Trivia> {
+        self.tokens().into_iter().flat_map(|token| token.comments()).collect()
+    }
+}
+
+/// Árvore sem perdas: `text()` devolve o código-fonte original, byte a byte.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxTree {
+    pub root: SyntaxNode,
+    /// Trivia depois da última quebra de linha.
+    pub eof: Vec<Trivia>,
+}
+
+impl SyntaxTree {
+    /// Faz a análise léxica e sintática e monta a árvore concreta ao lado da AST.
+    /// Com erros de sintaxe a árvore segue os nós de erro da AST e continua sem perdas.
+    pub fn parse(source: &str) -> (SyntaxTree, Vec<Stmt>, ErrorHandler) {
+        let (ast, errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+        let (tokens, eof) = lex_with_trivia(source);
+        (SyntaxTree::build(source, tokens, eof, &ast), ast, errors)
+    }
+
+    /// Agrupa os tokens nos nós usando os spans da AST.
+    pub fn build(source: &str, tokens: Vec<SyntaxToken>, eof: Vec<Trivia>, ast: &[Stmt]) -> SyntaxTree {
+        let program = Shape {
+            kind: SyntaxKind::Program,
+            span: Span::new(0, source.len()),
+            children: ast.iter().map(Shape::stmt).collect(),
+        };
+        let mut tokens = tokens.into_iter().peekable();
+        let root = program.build(&mut tokens);
+        SyntaxTree { root, eof }
+    }
+
+    pub fn text(&self) -> String {
+        let mut text = self.root.text();
+        for trivia in &self.eof {
+            text.push_str(&trivia.text);
+        }
+        text
+    }
+
+    pub fn tokens(&self) -> Vec<&SyntaxToken> {
+        self.root.tokens()
+    }
+
+    pub fn comments(&self) -> Vec<&Trivia> {
+        let mut comments = self.root.comments();
+        comments.extend(self.eof.iter().filter(|trivia| trivia.kind == Token::Comment));
+        comments
+    }
+}
+
+/// Esqueleto de um nó: tipo, span e filhos, tirado da AST.
+struct Shape {
+    kind: SyntaxKind,
+    span: Span,
+    children: Vec<Shape>,
+}
+
+impl Shape {
+    fn stmt(stmt: &Stmt) -> Shape {
+        let (kind, children) = match stmt {
+            Stmt::Command(..) => (SyntaxKind::Command, Vec::new()),
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                let mut children = vec![Shape::expr(condition)];
+                children.extend(then_branch.iter().chain(else_branch).map(Shape::stmt));
+                (SyntaxKind::If, children)
+            }
+            Stmt::WhileStmt { condition, body, .. } => {
+                let mut children = vec![Shape::expr(condition)];
+                children.extend(body.iter().map(Shape::stmt));
+                (SyntaxKind::While, children)
+            }
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                let mut children = vec![Shape::stmt(init), Shape::expr(condition), Shape::stmt(update)];
+                children.extend(body.iter().map(Shape::stmt));
+                (SyntaxKind::For, children)
+            }
+            Stmt::LetStmt { value, .. } => (SyntaxKind::Let, vec![Shape::expr(value)]),
+            Stmt::AssignStmt { value, .. } => (SyntaxKind::Assign, vec![Shape::expr(value)]),
+            Stmt::ExprStmt(expr) => (SyntaxKind::ExprStmt, vec![Shape::expr(expr)]),
+            Stmt::Error(_) => (SyntaxKind::ErrorStmt, Vec::new()),
+        };
+        Shape { kind, span: stmt.span(), children }
+    }
+
+    fn expr(expr: &Expr) -> Shape {
+        let (kind, children) = match expr {
+            Expr::Identifier(..) => (SyntaxKind::Identifier, Vec::new()),
+            Expr::Number(..) => (SyntaxKind::Number, Vec::new()),
+            Expr::BinaryOp { left, right, .. } => (SyntaxKind::BinaryOp, vec![Shape::expr(left), Shape::expr(right)]),
+            Expr::UnaryOp { operand, .. } => (SyntaxKind::UnaryOp, vec![Shape::expr(operand)]),
+            Expr::Error(_) => (SyntaxKind::ErrorExpr, Vec::new()),
+        };
+        Shape { kind, span: expr.span(), children }
+    }
+
+    /// Consome os tokens que começam dentro do span. Tokens que não caem em
+    /// nenhum filho (palavras-chave, parênteses, `\n`) ficam neste nó.
+    fn build<I: Iterator<Item = SyntaxToken>>(self, tokens: &mut Peekable<I>) -> SyntaxNode {
+        let end = self.span.end;
+        let mut children = Vec::new();
+        let mut shapes = self.children.into_iter().peekable();
+        while let Some(start) = tokens.peek().map(|token| token.span.start).filter(|start| *start < end) {
+            match shapes.next_if(|shape| shape.span.start <= start) {
+                Some(shape) => children.push(SyntaxElement::Node(shape.build(tokens))),
+                None => children.extend(tokens.next().map(SyntaxElement::Token)),
+            }
+        }
+        // Filhos que ficaram sem tokens, como nós de erro vazios.
+        children.extend(shapes.map(|shape| SyntaxElement::Node(shape.build(tokens))));
+        SyntaxNode { kind: self.kind, span: self.span, children }
+    }
+}
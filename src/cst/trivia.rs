@@ -0,0 +1,92 @@
+use logos::Logos;
+
+use crate::lexer::token::Token;
+use crate::span::Span;
+
+/// Trecho que o lexer descarta: `Token::Whitespace` ou `Token::Comment`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia {
+    pub kind: Token,
+    pub text: String,
+    pub span: Span,
+}
+
+/// Token com a trivia ao redor.
+///
+/// A trivia depois de um token, na mesma linha, é `trailing` dele; a trivia
+/// no começo de uma linha é `leading` do primeiro token da linha. Como `\n`
+/// é um token, um comentário de fim de linha fica sempre no token antes dele.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxToken {
+    pub kind: Token,
+    pub text: String,
+    pub span: Span,
+    pub leading: Vec<Trivia>,
+    pub trailing: Vec<Trivia>,
+}
+
+impl SyntaxToken {
+    /// Texto do token junto com a trivia, exatamente como no código-fonte.
+    pub fn full_text(&self) -> String {
+        let mut text = String::new();
+        for trivia in &self.leading {
+            text.push_str(&trivia.text);
+        }
+        text.push_str(&self.text);
+        for trivia in &self.trailing {
+            text.push_str(&trivia.text);
+        }
+        text
+    }
+
+    pub fn comments(&self) -> impl Iterator<Item = &Trivia> {
+        self.leading.iter().chain(&self.trailing).filter(|trivia| trivia.kind == Token::Comment)
+    }
+}
+
+/// Tokeniza sem perder nada. Devolve os tokens e a trivia depois da última
+/// quebra de linha, que não pertence a nenhum token.
+///
+/// Tokens inválidos continuam como `Token::Error`; os erros léxicos são
+/// reportados pelo `Lexer`.
+pub fn lex_with_trivia(source: &str) -> (Vec<SyntaxToken>, Vec<Trivia>) {
+    let mut tokens: Vec<SyntaxToken> = Vec::new();
+    let mut offset = 0;
+
+    for (result, range) in Token::lexer(source).spanned() {
+        let mut leading = split_gap(source, offset, range.start);
+        if let Some(previous) = tokens.last_mut().filter(|token| token.kind != Token::Newline) {
+            previous.trailing.append(&mut leading);
+        }
+        tokens.push(SyntaxToken {
+            kind: result.unwrap_or(Token::Error),
+            text: source[range.clone()].to_string(),
+            span: Span::new(range.start, range.end),
+            leading,
+            trailing: Vec::new(),
+        });
+        offset = range.end;
+    }
+
+    let mut eof = split_gap(source, offset, source.len());
+    if let Some(last) = tokens.last_mut().filter(|token| token.kind != Token::Newline) {
+        last.trailing.append(&mut eof);
+    }
+    (tokens, eof)
+}
+
+/// Divide o espaço entre dois tokens em espaços e comentário. O comentário vai
+/// até o fim da linha, então só pode haver espaços antes dele.
+fn split_gap(source: &str, start: usize, end: usize) -> Vec<Trivia> {
+    let gap = &source[start..end];
+    let comment_start = gap.find("//").map_or(end, |index| start + index);
+    [(Token::Whitespace, start, comment_start), (Token::Comment, comment_start, end)]
+        .into_iter()
+        .filter(|(_, from, to)| from < to)
+        .map(|(kind, from, to)| Trivia {
+            kind,
+            text: source[from..to].to_string(),
+            span: Span::new(from, to),
+        })
+        .collect()
+}
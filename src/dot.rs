@@ -0,0 +1,153 @@
+use crate::cfg::cfg::{Cfg, Terminator};
+use crate::formatter::{format_expr, format_simple_stmt};
+use crate::parser::ast::{Expr, Stmt};
+
+/// Exporta a AST para Graphviz (`dot -Tsvg`): um nó por statement ou expressão,
+/// com as arestas nomeadas pelo papel do filho (`condition`, `then`, `else`, `body`, ...).
+/// Statements são caixas, expressões são elipses e trechos com erro ficam em vermelho.
+pub fn ast_to_dot(stmts: &[Stmt]) -> String {
+    let mut graph = AstGraph { out: String::new(), nodes: 0 };
+    graph.out.push_str("digraph ast {\n    ordering=out;\n    node [shape=box, fontname=\"monospace\"];\n");
+    let root = graph.node("program", "box", false);
+    graph.children(root, "", stmts);
+    graph.out.push_str("}\n");
+    graph.out
+}
+
+struct AstGraph {
+    out: String,
+    nodes: usize,
+}
+
+impl AstGraph {
+    fn node(&mut self, label: &str, shape: &str, error: bool) -> usize {
+        let id = self.nodes;
+        self.nodes += 1;
+        let color = if error { ", color=red, fontcolor=red" } else { "" };
+        self.out.push_str(&format!("    n{} [label=\"{}\", shape={}{}];\n", id, escape(label), shape, color));
+        id
+    }
+
+    fn edge(&mut self, from: usize, to: usize, label: &str) {
+        if label.is_empty() {
+            self.out.push_str(&format!("    n{} -> n{};\n", from, to));
+        } else {
+            self.out.push_str(&format!("    n{} -> n{} [label=\"{}\"];\n", from, to, label));
+        }
+    }
+
+    fn children(&mut self, parent: usize, label: &str, stmts: &[Stmt]) {
+        for stmt in stmts {
+            let child = self.stmt(stmt);
+            self.edge(parent, child, label);
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) -> usize {
+        match stmt {
+            Stmt::Command(command, _) => self.node(&command.to_string(), "box", false),
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                let id = self.node("if", "box", false);
+                let condition = self.expr(condition);
+                self.edge(id, condition, "condition");
+                self.children(id, "then", then_branch);
+                self.children(id, "else", else_branch);
+                id
+            }
+            Stmt::WhileStmt { condition, body, .. } => {
+                let id = self.node("while", "box", false);
+                let condition = self.expr(condition);
+                self.edge(id, condition, "condition");
+                self.children(id, "body", body);
+                id
+            }
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                let id = self.node("for", "box", false);
+                let init = self.stmt(init);
+                self.edge(id, init, "init");
+                let condition = self.expr(condition);
+                self.edge(id, condition, "condition");
+                let update = self.stmt(update);
+                self.edge(id, update, "update");
+                self.children(id, "body", body);
+                id
+            }
+            Stmt::LetStmt { name, var_type, value, .. } => {
+                let id = self.node(&format!("let {}: {}", name, var_type.keyword()), "box", false);
+                let value = self.expr(value);
+                self.edge(id, value, "value");
+                id
+            }
+            Stmt::AssignStmt { name, value, .. } => {
+                let id = self.node(&format!("{} =", name), "box", false);
+                let value = self.expr(value);
+                self.edge(id, value, "value");
+                id
+            }
+            Stmt::ExprStmt(expr) => {
+                let id = self.node("expr", "box", false);
+                let expr = self.expr(expr);
+                self.edge(id, expr, "");
+                id
+            }
+            Stmt::Error(_) => self.node("<error>", "box", true),
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) -> usize {
+        match expr {
+            Expr::Identifier(name, _) => self.node(name, "ellipse", false),
+            Expr::Number(n, _) => self.node(&n.to_string(), "ellipse", false),
+            Expr::BinaryOp { left, op, right, .. } => {
+                let id = self.node(&op.to_string(), "ellipse", false);
+                let left = self.expr(left);
+                self.edge(id, left, "");
+                let right = self.expr(right);
+                self.edge(id, right, "");
+                id
+            }
+            Expr::UnaryOp { op, operand, .. } => {
+                let id = self.node(&op.to_string(), "ellipse", false);
+                let operand = self.expr(operand);
+                self.edge(id, operand, "");
+                id
+            }
+            Expr::Error(_) => self.node("<error>", "ellipse", true),
+        }
+    }
+}
+
+/// Exporta o grafo de fluxo de controle: cada bloco básico é uma caixa com os seus
+/// statements e, no fim, a condição do desvio; as arestas de um desvio são `true` e `false`.
+pub fn cfg_to_dot(cfg: &Cfg) -> String {
+    let mut out = String::from("digraph cfg {\n    node [shape=box, fontname=\"monospace\"];\n");
+    for (id, block) in cfg.blocks.iter().enumerate() {
+        let mut lines = vec![match (id == cfg.entry, id == cfg.exit) {
+            (true, true) => format!("B{} (entry, exit)", id),
+            (true, false) => format!("B{} (entry)", id),
+            (false, true) => format!("B{} (exit)", id),
+            (false, false) => format!("B{}", id),
+        }];
+        lines.extend(block.stmts.iter().map(format_simple_stmt));
+        if let Terminator::Branch { condition, .. } = &block.terminator {
+            lines.push(format!("branch ({})", format_expr(condition)));
+        }
+        // `\l` quebra a linha alinhando o texto à esquerda.
+        let label: String = lines.iter().map(|line| format!("{}\\l", escape(line))).collect();
+        out.push_str(&format!("    b{} [label=\"{}\"];\n", id, label));
+        match &block.terminator {
+            Terminator::Goto(target) => out.push_str(&format!("    b{} -> b{};\n", id, target)),
+            Terminator::Branch { then_block, else_block, .. } => {
+                out.push_str(&format!("    b{} -> b{} [label=\"true\"];\n", id, then_block));
+                out.push_str(&format!("    b{} -> b{} [label=\"false\"];\n", id, else_block));
+            }
+            Terminator::Exit => {}
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -1,3 +1,6 @@
 pub mod error_handler;
 pub mod error_type;
-pub mod compiler_error;
\ No newline at end of file
+pub mod compiler_error;
+pub mod renderer;
+pub mod json_emitter;
+pub mod error_code;
\ No newline at end of file
@@ -1,5 +1,6 @@
 use std::fmt;
-use crate::error_handler::error_type::ErrorType;
+use crate::error_handler::{error_code::ErrorCode, error_type::ErrorType};
+use crate::span::Span;
 
 #[derive(Debug, Clone)]
 pub struct CompilerError {
@@ -7,6 +8,9 @@ pub struct CompilerError {
     pub message: String,
     pub line: usize,
     pub column: usize,
+    /// Trecho exato do código-fonte, quando a fase que gerou o erro o conhece.
+    pub span: Option<Span>,
+    pub code: Option<ErrorCode>,
 }
 
 impl CompilerError {
@@ -16,8 +20,23 @@ impl CompilerError {
             message: message.to_string(),
             line,
             column,
+            span: None,
+            code: None,
         }
     }
+
+    /// Erro identificado por um código; o tipo vem do próprio código.
+    pub fn coded(code: ErrorCode, message: &str, line: usize, column: usize) -> Self {
+        CompilerError {
+            code: Some(code),
+            ..CompilerError::new(code.error_type(), message, line, column)
+        }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
 }
 
 impl fmt::Display for CompilerError {
@@ -28,4 +47,4 @@ impl fmt::Display for CompilerError {
             self.error_type, self.message, self.line, self.column
         )
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,629 @@
+use std::fmt;
+
+use crate::error_handler::error_type::ErrorType;
+
+/// Código estável de cada diagnóstico, no formato `Q0000`.
+///
+/// A centena indica a fase: `Q00xx` léxico, `Q01xx` sintático, `Q02xx` semântico,
+/// `Q03xx` execução, `Q04xx` mapa, `Q05xx` assembler e `Q06xx` otimização; estes
+/// últimos são avisos do `--optimize`, não erros. Códigos nunca são reaproveitados.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    UnknownCharacter,
+    MalformedNumber,
+    NumberOutOfRange,
+
+    ExpectedOpenParen,
+    ExpectedCloseParen,
+    ExpectedOpenBrace,
+    ExpectedCloseBrace,
+    ExpectedSemicolon,
+    ExpectedExpression,
+    ExpectedStatement,
+    ExpectedVariableName,
+    ExpectedTypeAnnotation,
+    UnknownType,
+    ExpectedAssign,
+
+    UndefinedIdentifier,
+    RedeclaredPredefined,
+    AlreadyDeclared,
+    AssignToUndeclared,
+    AssignToPredefined,
+    OperandTypeMismatch,
+    EqualityTypeMismatch,
+    UnaryOperandTypeMismatch,
+    ConditionNotBoolean,
+    VariableTypeMismatch,
+
+    DivisionByZero,
+    IntegerOverflow,
+    StepLimitExceeded,
+    RuntimeTypeMismatch,
+    UndefinedAtRuntime,
+    UnparsedCode,
+
+    InvalidHealth,
+    UnknownHeaderKey,
+    MalformedHeaderLine,
+    MissingSeparator,
+    EmptyGrid,
+    RaggedRow,
+    UnknownCell,
+    MissingHero,
+    DuplicateHero,
+
+    UnknownMnemonic,
+    InvalidOperand,
+    JumpOutOfRange,
+    UnknownSlot,
+    StackImbalance,
+
+    ConstantFolded,
+    ConstantsCombined,
+    ConstantCondition,
+    LoopNeverRuns,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 48] = [
+        ErrorCode::UnknownCharacter,
+        ErrorCode::MalformedNumber,
+        ErrorCode::NumberOutOfRange,
+        ErrorCode::ExpectedOpenParen,
+        ErrorCode::ExpectedCloseParen,
+        ErrorCode::ExpectedOpenBrace,
+        ErrorCode::ExpectedCloseBrace,
+        ErrorCode::ExpectedSemicolon,
+        ErrorCode::ExpectedExpression,
+        ErrorCode::ExpectedStatement,
+        ErrorCode::ExpectedVariableName,
+        ErrorCode::ExpectedTypeAnnotation,
+        ErrorCode::UnknownType,
+        ErrorCode::ExpectedAssign,
+        ErrorCode::UndefinedIdentifier,
+        ErrorCode::RedeclaredPredefined,
+        ErrorCode::AlreadyDeclared,
+        ErrorCode::AssignToUndeclared,
+        ErrorCode::AssignToPredefined,
+        ErrorCode::OperandTypeMismatch,
+        ErrorCode::EqualityTypeMismatch,
+        ErrorCode::UnaryOperandTypeMismatch,
+        ErrorCode::ConditionNotBoolean,
+        ErrorCode::VariableTypeMismatch,
+        ErrorCode::DivisionByZero,
+        ErrorCode::IntegerOverflow,
+        ErrorCode::StepLimitExceeded,
+        ErrorCode::RuntimeTypeMismatch,
+        ErrorCode::UndefinedAtRuntime,
+        ErrorCode::UnparsedCode,
+        ErrorCode::InvalidHealth,
+        ErrorCode::UnknownHeaderKey,
+        ErrorCode::MalformedHeaderLine,
+        ErrorCode::MissingSeparator,
+        ErrorCode::EmptyGrid,
+        ErrorCode::RaggedRow,
+        ErrorCode::UnknownCell,
+        ErrorCode::MissingHero,
+        ErrorCode::DuplicateHero,
+        ErrorCode::UnknownMnemonic,
+        ErrorCode::InvalidOperand,
+        ErrorCode::JumpOutOfRange,
+        ErrorCode::UnknownSlot,
+        ErrorCode::StackImbalance,
+        ErrorCode::ConstantFolded,
+        ErrorCode::ConstantsCombined,
+        ErrorCode::ConstantCondition,
+        ErrorCode::LoopNeverRuns,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorCode::UnknownCharacter => "Q0001",
+            ErrorCode::MalformedNumber => "Q0002",
+            ErrorCode::NumberOutOfRange => "Q0003",
+
+            ErrorCode::ExpectedOpenParen => "Q0101",
+            ErrorCode::ExpectedCloseParen => "Q0102",
+            ErrorCode::ExpectedOpenBrace => "Q0103",
+            ErrorCode::ExpectedCloseBrace => "Q0104",
+            ErrorCode::ExpectedSemicolon => "Q0105",
+            ErrorCode::ExpectedExpression => "Q0106",
+            ErrorCode::ExpectedStatement => "Q0107",
+            ErrorCode::ExpectedVariableName => "Q0108",
+            ErrorCode::ExpectedTypeAnnotation => "Q0109",
+            ErrorCode::UnknownType => "Q0110",
+            ErrorCode::ExpectedAssign => "Q0111",
+
+            ErrorCode::UndefinedIdentifier => "Q0201",
+            ErrorCode::RedeclaredPredefined => "Q0202",
+            ErrorCode::AlreadyDeclared => "Q0203",
+            ErrorCode::AssignToUndeclared => "Q0204",
+            ErrorCode::AssignToPredefined => "Q0205",
+            ErrorCode::OperandTypeMismatch => "Q0206",
+            ErrorCode::EqualityTypeMismatch => "Q0207",
+            ErrorCode::UnaryOperandTypeMismatch => "Q0208",
+            ErrorCode::ConditionNotBoolean => "Q0209",
+            ErrorCode::VariableTypeMismatch => "Q0210",
+
+            ErrorCode::DivisionByZero => "Q0301",
+            ErrorCode::IntegerOverflow => "Q0302",
+            ErrorCode::StepLimitExceeded => "Q0303",
+            ErrorCode::RuntimeTypeMismatch => "Q0304",
+            ErrorCode::UndefinedAtRuntime => "Q0305",
+            ErrorCode::UnparsedCode => "Q0306",
+
+            ErrorCode::InvalidHealth => "Q0401",
+            ErrorCode::UnknownHeaderKey => "Q0402",
+            ErrorCode::MalformedHeaderLine => "Q0403",
+            ErrorCode::MissingSeparator => "Q0404",
+            ErrorCode::EmptyGrid => "Q0405",
+            ErrorCode::RaggedRow => "Q0406",
+            ErrorCode::UnknownCell => "Q0407",
+            ErrorCode::MissingHero => "Q0408",
+            ErrorCode::DuplicateHero => "Q0409",
+            ErrorCode::UnknownMnemonic => "Q0501",
+            ErrorCode::InvalidOperand => "Q0502",
+            ErrorCode::JumpOutOfRange => "Q0503",
+            ErrorCode::UnknownSlot => "Q0504",
+            ErrorCode::StackImbalance => "Q0505",
+            ErrorCode::ConstantFolded => "Q0601",
+            ErrorCode::ConstantsCombined => "Q0602",
+            ErrorCode::ConstantCondition => "Q0603",
+            ErrorCode::LoopNeverRuns => "Q0604",
+        }
+    }
+
+    /// Lê um código como `Q0001`; letras minúsculas também valem.
+    pub fn parse(text: &str) -> Option<ErrorCode> {
+        let text = text.trim().to_ascii_uppercase();
+        ErrorCode::ALL.into_iter().find(|code| code.code() == text)
+    }
+
+    pub fn error_type(&self) -> ErrorType {
+        match self.code().as_bytes()[2] {
+            b'0' => ErrorType::Lexical,
+            b'1' => ErrorType::Syntactic,
+            b'2' => ErrorType::Semantic,
+            b'3' => ErrorType::Runtime,
+            b'4' => ErrorType::Map,
+            b'5' => ErrorType::Assembly,
+            b'6' => ErrorType::Optimization,
+            _ => unreachable!("code {} is outside the known ranges", self.code()),
+        }
+    }
+
+    /// Explicação longa mostrada por `--explain`.
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            ErrorCode::UnknownCharacter => "\
+A character that is not part of QuestLang was found.
+
+QuestLang only understands commands (move_up, jump, ...), keywords, identifiers,
+integer numbers, operators, parentheses, braces, ':', ';' and '//' comments.
+
+Erroneous example:
+
+    move_up @ jump
+
+Remove the character or, if it was meant to be part of a name, use only
+letters, digits and '_'.",
+
+            ErrorCode::MalformedNumber => "\
+A number is immediately followed by letters, as in `123abc`.
+
+Identifiers cannot start with a digit. Erroneous example:
+
+    let 2steps: int = 0
+
+Rename the variable so it starts with a letter (`steps2`), or separate the
+number from the name with a space or an operator.",
+
+            ErrorCode::NumberOutOfRange => "\
+An integer literal does not fit in a 32-bit signed integer.
+
+QuestLang integers go from -2147483648 to 2147483647. Erroneous example:
+
+    let gold: int = 99999999999
+
+Use a smaller value.",
+
+            ErrorCode::ExpectedOpenParen => "\
+The condition of `if`, `while` or `for` must be wrapped in parentheses.
+
+Erroneous example:
+
+    while enemy) { attack }
+
+Corrected:
+
+    while (enemy) { attack }",
+
+            ErrorCode::ExpectedCloseParen => "\
+A '(' was opened but the matching ')' is missing.
+
+Erroneous example:
+
+    if (hero { move_left }
+
+Corrected:
+
+    if (hero) { move_left }",
+
+            ErrorCode::ExpectedOpenBrace => "\
+Bodies of `if`, `else`, `while` and `for` must be blocks between braces.
+
+Erroneous example:
+
+    while (enemy) attack
+
+Corrected:
+
+    while (enemy) { attack }",
+
+            ErrorCode::ExpectedCloseBrace => "\
+A block was opened with '{' but never closed with '}'.
+
+Erroneous example:
+
+    if (trap) { jump
+    move_up
+
+Corrected:
+
+    if (trap) { jump }
+    move_up",
+
+            ErrorCode::ExpectedSemicolon => "\
+The three clauses of a `for` header are separated by ';'.
+
+Erroneous example:
+
+    for (let i: int = 0; i < 3 i = i + 1) { jump }
+
+Corrected:
+
+    for (let i: int = 0; i < 3; i = i + 1) { jump }",
+
+            ErrorCode::ExpectedExpression => "\
+An expression (a number, a name, or an operation between them) was expected,
+but something else was found.
+
+Erroneous example:
+
+    hero + 10 -
+
+The operator '-' needs a right-hand operand:
+
+    hero + 10 - 2",
+
+            ErrorCode::ExpectedStatement => "\
+A statement was expected: a command, `if`, `while`, `for`, `let`, an
+assignment or an expression.
+
+Erroneous example:
+
+    } else { jump }
+
+An `else` must come right after the '}' of an `if` block.",
+
+            ErrorCode::ExpectedVariableName => "\
+`let` must be followed by the name of the new variable.
+
+Erroneous example:
+
+    let : int = 3
+
+Corrected:
+
+    let steps: int = 3",
+
+            ErrorCode::ExpectedTypeAnnotation => "\
+Every variable declaration needs a type after the name, separated by ':'.
+
+Erroneous example:
+
+    let steps = 3
+
+Corrected:
+
+    let steps: int = 3",
+
+            ErrorCode::UnknownType => "\
+QuestLang has two types: `int` and `bool`.
+
+Erroneous example:
+
+    let steps: number = 3
+
+Corrected:
+
+    let steps: int = 3",
+
+            ErrorCode::ExpectedAssign => "\
+A declaration or assignment is missing its '='.
+
+Erroneous example:
+
+    let steps: int 3
+
+Corrected:
+
+    let steps: int = 3",
+
+            ErrorCode::UndefinedIdentifier => "\
+A name was used that is neither a declared variable nor a predefined symbol
+(`hero`, `enemy`, `treasure`, `trap`).
+
+Erroneous example:
+
+    if (dragon) { attack }
+
+Declare the variable with `let` before using it, or fix the spelling.
+Remember that variables declared inside a block are not visible after it.",
+
+            ErrorCode::RedeclaredPredefined => "\
+`hero`, `enemy`, `treasure` and `trap` are predefined by the world and cannot
+be declared again.
+
+Erroneous example:
+
+    let hero: int = 3
+
+Choose another name for the variable.",
+
+            ErrorCode::AlreadyDeclared => "\
+A variable with the same name is already visible at this point.
+
+Erroneous example:
+
+    let steps: int = 1
+    if (hero) { let steps: int = 2 }
+
+Use an assignment (`steps = 2`) to change the existing variable, or pick a
+different name.",
+
+            ErrorCode::AssignToUndeclared => "\
+A value was assigned to a variable that was never declared.
+
+Erroneous example:
+
+    steps = 4
+
+Declare it first:
+
+    let steps: int = 4",
+
+            ErrorCode::AssignToPredefined => "\
+Predefined symbols are read from the world and cannot be assigned.
+
+Erroneous example:
+
+    trap = enemy
+
+Store the value in a variable of your own instead:
+
+    let danger: bool = enemy",
+
+            ErrorCode::OperandTypeMismatch => "\
+An operator received operands of the wrong type. Arithmetic and comparison
+operators need `int` operands; `&&` and `||` need `bool` operands.
+
+Erroneous example:
+
+    hero + enemy
+
+`enemy` is a `bool`, so it cannot be added to a number.",
+
+            ErrorCode::EqualityTypeMismatch => "\
+`==` and `!=` compare values of the same type.
+
+Erroneous example:
+
+    if (hero == enemy) { attack }
+
+`hero` is an `int` and `enemy` is a `bool`.",
+
+            ErrorCode::UnaryOperandTypeMismatch => "\
+`!` expects a `bool` operand and unary `-` expects an `int` operand.
+
+Erroneous example:
+
+    -enemy",
+
+            ErrorCode::ConditionNotBoolean => "\
+With the boolean-only condition rule, conditions of `if`, `while` and `for`
+must be `bool` expressions.
+
+Erroneous example:
+
+    while (hero) { defend }
+
+Corrected:
+
+    while (hero > 0) { defend }",
+
+            ErrorCode::VariableTypeMismatch => "\
+The value given to a variable does not match its declared type.
+
+Erroneous example:
+
+    let steps: int = enemy
+
+`enemy` is a `bool`, but `steps` was declared as `int`.",
+
+            ErrorCode::DivisionByZero => "\
+The program divided a number by zero while running.
+
+Erroneous example:
+
+    hero / (treasure - treasure)
+
+Check the divisor before dividing.",
+
+            ErrorCode::IntegerOverflow => "\
+An arithmetic operation produced a value outside the 32-bit integer range.
+
+Erroneous example:
+
+    2147483647 + 1",
+
+            ErrorCode::StepLimitExceeded => "\
+The program ran more commands and loop iterations than allowed, which
+usually means a loop never ends.
+
+Erroneous example:
+
+    while (hero) { defend }
+
+Make sure the loop condition eventually becomes false.",
+
+            ErrorCode::RuntimeTypeMismatch => "\
+A value of the wrong type reached a variable or an operator while running.
+This normally means the program skipped the type checker.",
+
+            ErrorCode::UndefinedAtRuntime => "\
+A name that was never declared was read or assigned while running.
+This normally means the program skipped the semantic analysis.",
+
+            ErrorCode::UnparsedCode => "\
+Execution reached a piece of code that failed to parse. The parser keeps
+such code in the tree as an error node so later checks can still run, but
+it cannot be executed. Fix the syntax errors first.",
+
+            ErrorCode::InvalidHealth => "\
+The `health` header of a map must be a positive integer.
+
+Erroneous example:
+
+    health: -3",
+
+            ErrorCode::UnknownHeaderKey => "\
+Map headers only accept the keys `name` and `health`.
+
+Erroneous example:
+
+    color: blue",
+
+            ErrorCode::MalformedHeaderLine => "\
+Each line of a map header must have the form `key: value`.
+
+Erroneous example:
+
+    health 5
+
+Corrected:
+
+    health: 5",
+
+            ErrorCode::MissingSeparator => "\
+The map header and the grid must be separated by a line containing only `---`.",
+
+            ErrorCode::EmptyGrid => "\
+The map has no grid rows after the `---` separator.",
+
+            ErrorCode::RaggedRow => "\
+Every row of the map grid must have the same number of cells as the first row.",
+
+            ErrorCode::UnknownCell => "\
+The map grid contains a character that is not in the legend:
+`#` wall, `.` floor, `H` hero, `E` enemy, `T` treasure, `^` trap.",
+
+            ErrorCode::MissingHero => "\
+The map grid must contain exactly one hero, marked with `H`.",
+
+            ErrorCode::DuplicateHero => "\
+The map grid contains more than one `H`; there can be only one hero.",
+
+            ErrorCode::UnknownMnemonic => "\
+A line of bytecode assembly starts with a word that is not an instruction
+or a directive. Instructions are written in lowercase, for example:
+
+    push_int 3
+    jump_if_false 7
+    command move_up",
+
+            ErrorCode::InvalidOperand => "\
+An instruction has a missing, extra or malformed operand. Each instruction
+takes a fixed list of operands:
+
+    push_int 3          an integer
+    init 0 int          a slot and a type (`int` or `bool`)
+    command jump        a command keyword
+    fail Q0201 \"msg\"   an error code and a quoted message",
+
+            ErrorCode::JumpOutOfRange => "\
+A jump points past the end of the program. Jump targets are instruction
+indices; the index right after the last instruction is allowed and ends
+the program.",
+
+            ErrorCode::UnknownSlot => "\
+An instruction uses a variable slot that was not declared. Every slot must
+be declared with a `.slot` directive before the instructions, in order:
+
+    .slot steps
+    push_int 3
+    init 0 int",
+
+            ErrorCode::StackImbalance => "\
+The instructions would pop from an empty stack, or two paths reach the same
+instruction with different stack depths. Every path must push a value before
+it is consumed, for example:
+
+    push_int 1
+    push_int 2
+    add",
+
+            ErrorCode::ConstantFolded => "\
+Warning from `--optimize`: an expression made only of numbers was replaced by
+its value. The program does the same thing; the warning shows what changed.
+
+    let steps: int = 10 - 5
+
+runs as:
+
+    let steps: int = 5
+
+Arithmetic that would fail when the program runs, like `1 / 0`, is kept as
+written so the error still happens at the same place.",
+
+            ErrorCode::ConstantsCombined => "\
+Warning from `--optimize`: numbers added to or subtracted from the same value
+were combined into one.
+
+    hero + 1 + 2
+
+runs as:
+
+    hero + 3
+
+Only numbers that move the value in the same direction are combined, so the
+new expression overflows exactly when the original one would.",
+
+            ErrorCode::ConstantCondition => "\
+Warning from `--optimize`: the condition of an `if` has the same value every
+time, so only one of its branches can run. The branch that never runs is
+removed, and when possible the `if` is replaced by the branch that does.
+
+    if (3 > 2) { jump } else { attack }
+
+runs as:
+
+    jump",
+
+            ErrorCode::LoopNeverRuns => "\
+Warning from `--optimize`: the condition of a `while` or `for` loop is false
+before the first iteration, so the body never runs and the loop is removed.
+
+    while (!1) { defend }
+
+The `init` of a `for` still runs once, so it is kept when it has an effect.",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -1,5 +1,9 @@
+use crate::error_handler::error_code::ErrorCode;
 use crate::error_handler::error_type::ErrorType;
 use crate::error_handler::compiler_error::CompilerError;
+use crate::error_handler::renderer::Renderer;
+use crate::parser::utils::offset_to_line_col;
+use crate::span::Span;
 
 #[derive(Default)]
 pub struct ErrorHandler {
@@ -18,14 +22,44 @@ impl ErrorHandler {
         self.errors.push(CompilerError::new(error_type, message, line, column));
     }
 
+    /// Registra um erro com código, sem span conhecido.
+    pub fn add_coded_error(&mut self, code: ErrorCode, message: &str, line: usize, column: usize) {
+        self.errors.push(CompilerError::coded(code, message, line, column));
+    }
+
+    /// Registra um erro com código e o span exato; linha e coluna vêm do início do span.
+    pub fn add_error_at(&mut self, code: ErrorCode, message: &str, span: Span, source: &str) {
+        let (line, column) = offset_to_line_col(source, span.start);
+        self.errors.push(CompilerError::coded(code, message, line, column).with_span(span));
+    }
+
     pub fn add_warning(&mut self, message: &str, line: usize, column: usize) {
         self.warnings.push(CompilerError::new(ErrorType::Semantic, message, line, column));
     }
 
+    pub fn add_warning_at(&mut self, message: &str, span: Span, source: &str) {
+        let (line, column) = offset_to_line_col(source, span.start);
+        self.warnings.push(CompilerError::new(ErrorType::Semantic, message, line, column).with_span(span));
+    }
+
+    /// Registra um warning com código e o span exato, como `add_error_at`.
+    pub fn add_coded_warning_at(&mut self, code: ErrorCode, message: &str, span: Span, source: &str) {
+        let (line, column) = offset_to_line_col(source, span.start);
+        self.warnings.push(CompilerError::coded(code, message, line, column).with_span(span));
+    }
+
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
     }
 
+    pub fn errors(&self) -> &[CompilerError] {
+        &self.errors
+    }
+
+    pub fn warnings(&self) -> &[CompilerError] {
+        &self.warnings
+    }
+
     pub fn report(&self) {
         if self.has_errors() {
             println!("🛑 Erros encontrados:");
@@ -44,6 +78,24 @@ impl ErrorHandler {
         }
     }
 
+    /// Como `report`, mas mostrando o trecho do código-fonte de cada diagnóstico.
+    pub fn report_with(&self, renderer: &Renderer) {
+        print!("{}", self.render(renderer));
+    }
+
+    /// Renderiza erros e depois warnings, separados por uma linha em branco.
+    pub fn render(&self, renderer: &Renderer) -> String {
+        let errors = self.errors.iter().map(|error| renderer.render_error(error));
+        let warnings = self.warnings.iter().map(|warning| renderer.render_warning(warning));
+        errors.chain(warnings).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Junta os diagnósticos de outra fase a este handler.
+    pub fn extend(&mut self, other: ErrorHandler) {
+        self.errors.extend(other.errors);
+        self.warnings.extend(other.warnings);
+    }
+
     pub fn clear(&mut self) {
         self.errors.clear();
         self.warnings.clear();
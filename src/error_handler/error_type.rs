@@ -4,4 +4,8 @@ pub enum ErrorType {
     Lexical,
     Syntactic,
     Semantic,
+    Runtime,
+    Map,
+    Assembly,
+    Optimization,
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+use crate::error_handler::compiler_error::CompilerError;
+use crate::error_handler::error_handler::ErrorHandler;
+use crate::json::Json;
+use crate::parser::utils::offset_to_line_col;
+
+/// Emite diagnósticos em JSON Lines: um objeto por linha, erros antes dos warnings.
+///
+/// Formato de cada objeto, sempre com as mesmas chaves e nesta ordem:
+///
+/// ```text
+/// {"type":"Syntactic","severity":"error","code":"Q0102","message":"...",
+///  "file":"main.quest","line_start":2,"column_start":5,"line_end":2,"column_end":11}
+/// ```
+///
+/// `file` e `code` são `null` quando não foram informados. Sem span, o fim é igual ao início;
+/// `line_start` 0 indica um diagnóstico sem posição.
+pub struct JsonEmitter<'a> {
+    source: &'a str,
+    file: Option<&'a str>,
+}
+
+impl<'a> JsonEmitter<'a> {
+    pub fn new(source: &'a str) -> Self {
+        JsonEmitter { source, file: None }
+    }
+
+    pub fn with_file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    pub fn emit(&self, handler: &ErrorHandler) -> String {
+        let errors = handler.errors().iter().map(|error| self.to_json(error, "error"));
+        let warnings = handler.warnings().iter().map(|warning| self.to_json(warning, "warning"));
+        errors.chain(warnings).map(|json| format!("{}\n", json)).collect()
+    }
+
+    pub fn to_json(&self, error: &CompilerError, severity: &str) -> Json {
+        let (line_end, column_end) = match error.span {
+            Some(span) => offset_to_line_col(self.source, span.end),
+            None => (error.line, error.column),
+        };
+        Json::object(vec![
+            ("type", Json::string(&format!("{:?}", error.error_type))),
+            ("severity", Json::string(severity)),
+            ("code", error.code.map_or(Json::Null, |code| Json::string(code.code()))),
+            ("message", Json::string(&error.message)),
+            ("file", self.file.map_or(Json::Null, Json::string)),
+            ("line_start", Json::Number(error.line as i64)),
+            ("column_start", Json::Number(error.column as i64)),
+            ("line_end", Json::Number(line_end as i64)),
+            ("column_end", Json::Number(column_end as i64)),
+        ])
+    }
+}
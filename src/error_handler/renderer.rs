@@ -0,0 +1,114 @@
+use crate::error_handler::compiler_error::CompilerError;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// Renderiza diagnósticos no estilo do rustc: cabeçalho, `-->` com a posição,
+/// a linha do código-fonte com o número na margem e `^^^` sob o trecho.
+pub struct Renderer<'a> {
+    source: &'a str,
+    file: Option<&'a str>,
+    color: bool,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Renderer {
+            source,
+            file: None,
+            color: false,
+        }
+    }
+
+    /// Nome do arquivo mostrado na linha `-->`.
+    pub fn with_file(mut self, file: &'a str) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Liga as cores ANSI.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn render_error(&self, error: &CompilerError) -> String {
+        let severity = match error.code {
+            Some(code) => format!("error[{}]", code),
+            None => format!("error[{:?}]", error.error_type),
+        };
+        self.render(&severity, RED, error)
+    }
+
+    pub fn render_warning(&self, warning: &CompilerError) -> String {
+        let severity = match warning.code {
+            Some(code) => format!("warning[{}]", code),
+            None => "warning".to_string(),
+        };
+        self.render(&severity, YELLOW, warning)
+    }
+
+    fn render(&self, severity: &str, accent: &str, error: &CompilerError) -> String {
+        let mut out = format!(
+            "{}: {}\n",
+            self.paint(accent, severity),
+            self.paint(BOLD, &error.message)
+        );
+        // Erros sem posição (linha 0) ficam só com o cabeçalho.
+        if error.line == 0 {
+            return out;
+        }
+
+        let line_number = error.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let location = match self.file {
+            Some(file) => format!("{}:{}:{}", file, error.line, error.column),
+            None => format!("{}:{}", error.line, error.column),
+        };
+        let text = self.source.lines().nth(error.line - 1).unwrap_or("");
+
+        out.push_str(&format!("{}{} {}\n", gutter, self.paint(BLUE, "-->"), location));
+        out.push_str(&format!("{} {}\n", gutter, self.paint(BLUE, "|")));
+        out.push_str(&format!("{} {}\n", self.paint(BLUE, &format!("{} |", line_number)), text));
+        out.push_str(&format!(
+            "{} {} {}{}\n",
+            gutter,
+            self.paint(BLUE, "|"),
+            indentation(text, error.column),
+            self.paint(accent, &"^".repeat(self.underline_width(error, text)))
+        ));
+        out
+    }
+
+    /// Quantos caracteres do span cabem na linha do erro; pelo menos um.
+    fn underline_width(&self, error: &CompilerError, text: &str) -> usize {
+        let Some(span) = error.span else {
+            return 1;
+        };
+        let remaining = text.chars().count().saturating_sub(error.column - 1);
+        let width = self
+            .source
+            .get(span.start..span.end)
+            .map_or(0, |snippet| snippet.chars().take_while(|ch| *ch != '\n').count());
+        width.min(remaining).max(1)
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Espaços até a coluna do erro, mantendo tabs para alinhar com a linha original.
+fn indentation(text: &str, column: usize) -> String {
+    text.chars()
+        .take(column.saturating_sub(1))
+        .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+        .collect()
+}
@@ -0,0 +1,235 @@
+use crate::cst::cst::SyntaxTree;
+use crate::cst::trivia::Trivia;
+use crate::error_handler::error_handler::ErrorHandler;
+use crate::lexer::token::Token;
+use crate::parser::ast::{Expr, Stmt};
+
+const INDENT: &str = "    ";
+
+/// Escreve a AST de volta como QuestLang válido, no estilo canônico:
+/// um statement por linha, quatro espaços de indentação, `{` na mesma linha
+/// do cabeçalho, `} else {` e parênteses só onde a precedência exige.
+///
+/// Reanalisar a saída produz a mesma AST (a menos dos spans). Com
+/// `with_syntax_tree`, os comentários do código original são mantidos.
+#[derive(Default)]
+pub struct Formatter {
+    lines: Vec<String>,
+    comments: Vec<Comment>,
+    next_comment: usize,
+    /// Posição de cada `{` e do `}` correspondente, em ordem.
+    blocks: Vec<(usize, usize)>,
+}
+
+/// Comentário do código original. `anchor` é o fim do token que ele segue na
+/// mesma linha; comentários em linha própria não têm âncora.
+struct Comment {
+    text: String,
+    start: usize,
+    anchor: Option<usize>,
+}
+
+impl Formatter {
+    pub fn new() -> Self {
+        Formatter::default()
+    }
+
+    /// Usa a árvore concreta do mesmo código para recolocar os comentários.
+    pub fn with_syntax_tree(mut self, tree: &SyntaxTree) -> Self {
+        let mut open = Vec::new();
+        for token in tree.tokens() {
+            self.add_comments(&token.leading, None);
+            self.add_comments(&token.trailing, Some(token.span.end));
+            match token.kind {
+                Token::LBrace => {
+                    open.push(self.blocks.len());
+                    self.blocks.push((token.span.start, token.span.start));
+                }
+                Token::RBrace => {
+                    if let Some(index) = open.pop() {
+                        self.blocks[index].1 = token.span.start;
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.add_comments(&tree.eof, None);
+        self
+    }
+
+    fn add_comments(&mut self, trivia: &[Trivia], anchor: Option<usize>) {
+        for comment in trivia.iter().filter(|trivia| trivia.kind == Token::Comment) {
+            self.comments.push(Comment {
+                text: comment.text.trim_end().to_string(),
+                start: comment.span.start,
+                anchor,
+            });
+        }
+    }
+
+    pub fn format(&mut self, stmts: &[Stmt]) -> String {
+        self.lines.clear();
+        self.next_comment = 0;
+        for stmt in stmts {
+            self.format_stmt(stmt, 0);
+        }
+        self.flush_comments(usize::MAX, 0);
+        let mut output = self.lines.join("\n");
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        output
+    }
+
+    fn format_stmt(&mut self, stmt: &Stmt, level: usize) {
+        let indent = INDENT.repeat(level);
+        self.flush_comments(stmt.span().start, level);
+        match stmt {
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                let header = format!("{}if ({})", indent, format_expr(condition));
+                self.format_if_rest(header, condition, then_branch, else_branch, level);
+            }
+            Stmt::WhileStmt { condition, body, .. } => {
+                let header = format!("{}while ({})", indent, format_expr(condition));
+                let block = self.block_after(condition.span().end);
+                self.format_block(header, body, level, block);
+                self.close_block(level, block);
+            }
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                let header = format!(
+                    "{}for ({}; {}; {})",
+                    indent,
+                    format_simple_stmt(init),
+                    format_expr(condition),
+                    format_simple_stmt(update)
+                );
+                let block = self.block_after(update.span().end);
+                self.format_block(header, body, level, block);
+                self.close_block(level, block);
+            }
+            _ => {
+                self.lines.push(format!("{}{}", indent, format_simple_stmt(stmt)));
+                self.trailing_comment(stmt.span().end);
+            }
+        }
+    }
+
+    /// Junta `else { if ... }` em `else if`, como o parser já faz na AST.
+    fn format_if_rest(&mut self, header: String, condition: &Expr, then_branch: &[Stmt], else_branch: &[Stmt], level: usize) {
+        let block = self.block_after(condition.span().end);
+        self.format_block(header, then_branch, level, block);
+        let close = block.map(|(_, close)| close);
+        match else_branch {
+            [] => self.close_block(level, block),
+            [Stmt::IfStmt { condition, then_branch, else_branch, .. }] => {
+                self.flush_comments(close.unwrap_or(0), level + 1);
+                let header = format!("{}}} else if ({})", INDENT.repeat(level), format_expr(condition));
+                self.format_if_rest(header, condition, then_branch, else_branch, level);
+            }
+            _ => {
+                self.flush_comments(close.unwrap_or(0), level + 1);
+                let else_block = close.and_then(|close| self.block_after(close + 1));
+                self.format_block(format!("{}}} else", INDENT.repeat(level)), else_branch, level, else_block);
+                self.close_block(level, else_block);
+            }
+        }
+    }
+
+    /// Abre o bloco no fim do cabeçalho e escreve o corpo; quem chama fecha o `}`.
+    fn format_block(&mut self, header: String, body: &[Stmt], level: usize, block: Option<(usize, usize)>) {
+        self.lines.push(format!("{} {{", header));
+        if let Some((open, _)) = block {
+            self.trailing_comment(open + 1);
+        }
+        for stmt in body {
+            self.format_stmt(stmt, level + 1);
+        }
+    }
+
+    /// Comentários que sobraram no fim do bloco ficam antes do `}`.
+    fn close_block(&mut self, level: usize, block: Option<(usize, usize)>) {
+        if let Some((_, close)) = block {
+            self.flush_comments(close, level + 1);
+        }
+        self.lines.push(format!("{}}}", INDENT.repeat(level)));
+        if let Some((_, close)) = block {
+            self.trailing_comment(close + 1);
+        }
+    }
+
+    /// O primeiro bloco que abre depois de `offset`.
+    fn block_after(&self, offset: usize) -> Option<(usize, usize)> {
+        self.blocks.iter().find(|(open, _)| *open >= offset).copied()
+    }
+
+    /// Escreve em linhas próprias os comentários que começam antes de `offset`.
+    fn flush_comments(&mut self, offset: usize, level: usize) {
+        while let Some(comment) = self.comments.get(self.next_comment).filter(|comment| comment.start < offset) {
+            self.lines.push(format!("{}{}", INDENT.repeat(level), comment.text));
+            self.next_comment += 1;
+        }
+    }
+
+    /// Mantém na mesma linha o comentário que seguia o token terminado em `end`.
+    fn trailing_comment(&mut self, end: usize) {
+        let Some(comment) = self.comments.get(self.next_comment).filter(|comment| comment.anchor == Some(end)) else {
+            return;
+        };
+        if let Some(line) = self.lines.last_mut() {
+            line.push(' ');
+            line.push_str(&comment.text);
+        }
+        self.next_comment += 1;
+    }
+}
+
+/// Statements de uma linha, que também aparecem no cabeçalho de um `for`.
+pub(crate) fn format_simple_stmt(stmt: &Stmt) -> String {
+    match stmt {
+        Stmt::Command(command, _) => command.to_string(),
+        Stmt::LetStmt { name, var_type, value, .. } => {
+            format!("let {}: {} = {}", name, var_type.keyword(), format_expr(value))
+        }
+        Stmt::AssignStmt { name, value, .. } => format!("{} = {}", name, format_expr(value)),
+        Stmt::ExprStmt(expr) => format_expr(expr),
+        Stmt::Error(_) => "<error>".to_string(),
+        Stmt::IfStmt { .. } | Stmt::WhileStmt { .. } | Stmt::ForStmt { .. } => {
+            let mut formatter = Formatter::new();
+            formatter.format_stmt(stmt, 0);
+            formatter.lines.join(" ")
+        }
+    }
+}
+
+pub(crate) fn format_expr(expr: &Expr) -> String {
+    match expr {
+        Expr::Identifier(name, _) => name.clone(),
+        Expr::Number(n, _) => n.to_string(),
+        Expr::Error(_) => "<error>".to_string(),
+        Expr::BinaryOp { left, op, right, .. } => format!(
+            "{} {} {}",
+            format_operand(left, op.precedence()),
+            op,
+            format_operand(right, op.precedence() + 1)
+        ),
+        Expr::UnaryOp { op, operand, .. } => format!("{}{}", op, format_operand(operand, expr.precedence())),
+    }
+}
+
+fn format_operand(expr: &Expr, min_precedence: u8) -> String {
+    if expr.precedence() < min_precedence {
+        format!("({})", format_expr(expr))
+    } else {
+        format_expr(expr)
+    }
+}
+
+/// Formata um programa a partir do código-fonte, mantendo os comentários.
+/// Programas com erros léxicos ou sintáticos não são formatados; os erros são devolvidos.
+pub fn format_source(source: &str) -> Result<String, ErrorHandler> {
+    let (tree, ast, errors) = SyntaxTree::parse(source);
+    if errors.has_errors() {
+        return Err(errors);
+    }
+    Ok(Formatter::new().with_syntax_tree(&tree).format(&ast))
+}
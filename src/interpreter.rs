@@ -0,0 +1,3 @@
+pub mod interpreter;
+pub mod trace;
+pub mod value;
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use crate::{
+    error_handler::{error_code::ErrorCode, error_handler::ErrorHandler},
+    interpreter::{
+        trace::{Trace, TraceEvent},
+        value::Value,
+    },
+    parser::ast::{BinOp, Command, Expr, Span, Stmt, UnaryOp},
+    world::world::World,
+};
+
+pub const DEFAULT_MAX_STEPS: usize = 10_000;
+
+/// Motivo pelo qual a execução parou antes do fim do programa.
+pub(crate) enum Halt {
+    Defeated,
+    Error(ErrorCode, String, Span),
+}
+
+/// O que o `Interpreter` e a `Vm` têm em comum: o `World`, os eventos do trace e
+/// o limite de passos.
+pub(crate) struct Execution {
+    pub(crate) world: World,
+    pub(crate) max_steps: usize,
+    events: Vec<TraceEvent>,
+    steps: usize,
+}
+
+impl Execution {
+    pub(crate) fn new(world: World) -> Self {
+        Execution {
+            world,
+            max_steps: DEFAULT_MAX_STEPS,
+            events: Vec::new(),
+            steps: 0,
+        }
+    }
+
+    /// Executa o comando no mundo e registra o evento; para se o herói for derrotado.
+    pub(crate) fn command(&mut self, command: &Command, span: Span) -> Result<(), Halt> {
+        self.tick(span)?;
+        let outcome = self.world.perform(command);
+        self.events.push(TraceEvent {
+            step: self.events.len() + 1,
+            command: command.clone(),
+            outcome,
+            position: self.world.hero.position,
+            health: self.world.hero.health,
+        });
+        if self.world.hero_defeated() {
+            return Err(Halt::Defeated);
+        }
+        Ok(())
+    }
+
+    /// Conta um passo (comando ou iteração de laço).
+    pub(crate) fn tick(&mut self, span: Span) -> Result<(), Halt> {
+        self.steps += 1;
+        if self.steps > self.max_steps {
+            return Err(Halt::Error(
+                ErrorCode::StepLimitExceeded,
+                format!("Execution exceeded the limit of {} steps", self.max_steps),
+                span,
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn into_trace(self) -> Trace {
+        Trace {
+            events: self.events,
+            world: self.world,
+        }
+    }
+}
+
+/// Executa a AST diretamente, movendo o herói pelo `World`.
+pub struct Interpreter<'a> {
+    execution: Execution,
+    source: Option<&'a str>,
+    scopes: Vec<HashMap<String, Value>>,
+    errors: ErrorHandler,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(world: World) -> Self {
+        Interpreter {
+            execution: Execution::new(world),
+            source: None,
+            scopes: vec![HashMap::new()],
+            errors: ErrorHandler::new(),
+        }
+    }
+
+    /// Limita comandos e iterações de laço, para que laços infinitos terminem.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.execution.max_steps = max_steps;
+        self
+    }
+
+    /// Fonte do programa, usada para dar linha e coluna aos erros de execução.
+    pub fn with_source(mut self, source: &'a str) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn run(mut self, stmts: &[Stmt]) -> (Trace, ErrorHandler) {
+        if let Err(Halt::Error(code, msg, span)) = self.exec_block(stmts) {
+            match self.source {
+                Some(source) => self.errors.add_error_at(code, &msg, span, source),
+                None => self.errors.add_coded_error(code, &msg, 0, 0),
+            }
+        }
+        (self.execution.into_trace(), self.errors)
+    }
+
+    fn exec_block(&mut self, stmts: &[Stmt]) -> Result<(), Halt> {
+        for stmt in stmts {
+            self.exec_stmt(stmt)?;
+        }
+        Ok(())
+    }
+
+    fn exec_scoped(&mut self, stmts: &[Stmt]) -> Result<(), Halt> {
+        self.scopes.push(HashMap::new());
+        let result = self.exec_block(stmts);
+        self.scopes.pop();
+        result
+    }
+
+    fn exec_stmt(&mut self, stmt: &Stmt) -> Result<(), Halt> {
+        match stmt {
+            Stmt::Command(command, span) => self.execution.command(command, *span),
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                if self.eval(condition)?.is_truthy() {
+                    self.exec_scoped(then_branch)
+                } else {
+                    self.exec_scoped(else_branch)
+                }
+            }
+            Stmt::WhileStmt { condition, body, span } => {
+                while self.eval(condition)?.is_truthy() {
+                    self.execution.tick(*span)?;
+                    self.exec_scoped(body)?;
+                }
+                Ok(())
+            }
+            Stmt::ForStmt { init, condition, update, body, span } => {
+                self.scopes.push(HashMap::new());
+                let result = self.exec_for(init, condition, update, body, *span);
+                self.scopes.pop();
+                result
+            }
+            Stmt::LetStmt { name, var_type, value, span, .. } => {
+                let value = self.eval(value)?;
+                if value.symbol_type() != *var_type {
+                    return Err(Halt::Error(
+                        ErrorCode::RuntimeTypeMismatch,
+                        format!("Cannot initialize '{}' of type {:?} with {}", name, var_type, value),
+                        *span,
+                    ));
+                }
+                if let Some(scope) = self.scopes.last_mut() {
+                    scope.insert(name.clone(), value);
+                }
+                Ok(())
+            }
+            Stmt::AssignStmt { name, value, span, .. } => {
+                let value = self.eval(value)?;
+                self.assign(name, value, *span)
+            }
+            Stmt::ExprStmt(expr) => {
+                self.eval(expr)?;
+                Ok(())
+            }
+            Stmt::Error(span) => Err(unparsed(*span)),
+        }
+    }
+
+    fn exec_for(&mut self, init: &Stmt, condition: &Expr, update: &Stmt, body: &[Stmt], span: Span) -> Result<(), Halt> {
+        self.exec_stmt(init)?;
+        while self.eval(condition)?.is_truthy() {
+            self.execution.tick(span)?;
+            self.exec_scoped(body)?;
+            self.exec_stmt(update)?;
+        }
+        Ok(())
+    }
+
+    fn assign(&mut self, name: &str, value: Value, span: Span) -> Result<(), Halt> {
+        let Some(slot) = self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name)) else {
+            return Err(Halt::Error(
+                ErrorCode::UndefinedAtRuntime,
+                format!("Assignment to undeclared variable '{}'", name),
+                span,
+            ));
+        };
+        if slot.symbol_type() != value.symbol_type() {
+            return Err(Halt::Error(
+                ErrorCode::RuntimeTypeMismatch,
+                format!("Cannot assign {} to '{}' of type {:?}", value, name, slot.symbol_type()),
+                span,
+            ));
+        }
+        *slot = value;
+        Ok(())
+    }
+
+    fn eval(&mut self, expr: &Expr) -> Result<Value, Halt> {
+        match expr {
+            Expr::Identifier(name, span) => self.lookup(name, *span),
+            Expr::Number(n, _) => Ok(Value::Int(*n)),
+            Expr::Error(span) => Err(unparsed(*span)),
+            Expr::BinaryOp { left, op: BinOp::And, right, .. } => {
+                if !self.eval(left)?.is_truthy() {
+                    return Ok(Value::Bool(false));
+                }
+                Ok(Value::Bool(self.eval(right)?.is_truthy()))
+            }
+            Expr::BinaryOp { left, op: BinOp::Or, right, .. } => {
+                if self.eval(left)?.is_truthy() {
+                    return Ok(Value::Bool(true));
+                }
+                Ok(Value::Bool(self.eval(right)?.is_truthy()))
+            }
+            Expr::BinaryOp { left, op, right, span, .. } => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+                eval_binop(left, op, right, *span)
+            }
+            Expr::UnaryOp { op: UnaryOp::Not, operand, .. } => {
+                Ok(Value::Bool(!self.eval(operand)?.is_truthy()))
+            }
+            Expr::UnaryOp { op: UnaryOp::Neg, operand, span } => negate(self.eval(operand)?, *span),
+        }
+    }
+
+    fn lookup(&self, name: &str, span: Span) -> Result<Value, Halt> {
+        if let Some(value) = self.scopes.iter().rev().find_map(|scope| scope.get(name)) {
+            return Ok(*value);
+        }
+        self.execution.world.predefined(name).ok_or_else(|| {
+            Halt::Error(
+                ErrorCode::UndefinedAtRuntime,
+                format!("Undefined identifier '{}'", name),
+                span,
+            )
+        })
+    }
+}
+
+/// Operadores binários sem curto-circuito, compartilhados com a VM de bytecode.
+pub(crate) fn eval_binop(left: Value, op: &BinOp, right: Value, span: Span) -> Result<Value, Halt> {
+    match (left, right) {
+        (Value::Int(l), Value::Int(r)) => eval_int_binop(l, op, r, span),
+        (Value::Bool(l), Value::Bool(r)) if matches!(op, BinOp::Eq | BinOp::Ne) => {
+            Ok(Value::Bool((l == r) == (*op == BinOp::Eq)))
+        }
+        _ => Err(Halt::Error(
+            ErrorCode::RuntimeTypeMismatch,
+            format!("Operator '{}' cannot be applied to {} and {}", op, left, right),
+            span,
+        )),
+    }
+}
+
+fn eval_int_binop(l: i32, op: &BinOp, r: i32, span: Span) -> Result<Value, Halt> {
+    let overflow = || Halt::Error(ErrorCode::IntegerOverflow, format!("Integer overflow in '{}'", op), span);
+    match op {
+        BinOp::Add => l.checked_add(r).map(Value::Int).ok_or_else(overflow),
+        BinOp::Sub => l.checked_sub(r).map(Value::Int).ok_or_else(overflow),
+        BinOp::Mul => l.checked_mul(r).map(Value::Int).ok_or_else(overflow),
+        BinOp::Div if r == 0 => Err(Halt::Error(ErrorCode::DivisionByZero, "Division by zero".to_string(), span)),
+        BinOp::Div => l.checked_div(r).map(Value::Int).ok_or_else(overflow),
+        BinOp::Lt => Ok(Value::Bool(l < r)),
+        BinOp::Gt => Ok(Value::Bool(l > r)),
+        BinOp::Le => Ok(Value::Bool(l <= r)),
+        BinOp::Ge => Ok(Value::Bool(l >= r)),
+        BinOp::Eq => Ok(Value::Bool(l == r)),
+        BinOp::Ne => Ok(Value::Bool(l != r)),
+        BinOp::And | BinOp::Or => unreachable!("logical operators short-circuit before reaching here"),
+    }
+}
+
+pub(crate) fn negate(value: Value, span: Span) -> Result<Value, Halt> {
+    match value {
+        Value::Int(n) => n
+            .checked_neg()
+            .map(Value::Int)
+            .ok_or_else(|| Halt::Error(ErrorCode::IntegerOverflow, "Integer overflow in '-'".to_string(), span)),
+        other => Err(Halt::Error(
+            ErrorCode::RuntimeTypeMismatch,
+            format!("Operator '-' expects an integer, found {}", other),
+            span,
+        )),
+    }
+}
+
+fn unparsed(span: Span) -> Halt {
+    Halt::Error(ErrorCode::UnparsedCode, "Cannot run code that failed to parse".to_string(), span)
+}
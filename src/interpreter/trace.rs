@@ -0,0 +1,41 @@
+use std::fmt;
+
+use crate::parser::ast::Command;
+use crate::world::position::Position;
+use crate::world::world::{Outcome, World};
+
+/// Um comando executado, com o estado do herói logo depois dele.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEvent {
+    pub step: usize,
+    pub command: Command,
+    pub outcome: Outcome,
+    pub position: Position,
+    pub health: i32,
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#{} {} -> {:?} em ({}, {}), vida {}",
+            self.step, self.command, self.outcome, self.position.x, self.position.y, self.health
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    pub events: Vec<TraceEvent>,
+    pub world: World,
+}
+
+impl Trace {
+    pub fn commands(&self) -> Vec<Command> {
+        self.events.iter().map(|e| e.command.clone()).collect()
+    }
+
+    pub fn positions(&self) -> Vec<Position> {
+        self.events.iter().map(|e| e.position).collect()
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+use crate::symbol_table::symbol_type::SymbolType;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    Int(i32),
+    Bool(bool),
+}
+
+impl Value {
+    /// Inteiros são verdadeiros quando diferentes de zero.
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Value::Int(n) => *n != 0,
+            Value::Bool(b) => *b,
+        }
+    }
+
+    pub fn symbol_type(&self) -> SymbolType {
+        match self {
+            Value::Int(_) => SymbolType::Integer,
+            Value::Bool(_) => SymbolType::Boolean,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+        }
+    }
+}
@@ -1,20 +1,32 @@
+use crate::error_handler::{error_code::ErrorCode, error_handler::ErrorHandler};
 use crate::lexer::token::Token;
+use crate::span::Span;
 use logos::Logos;
 
 pub struct Lexer<'a> {
     lexer: logos::Lexer<'a, Token>,
+    errors: ErrorHandler,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Self {
         Lexer {
             lexer: Token::lexer(input),
+            errors: ErrorHandler::new(),
         }
     }
 
-    pub fn tokenize(&mut self) -> Vec<(Token, &str, usize)> {
+    /// Tokeniza a entrada inteira e devolve também os erros léxicos.
+    pub fn lex(mut self) -> (Vec<(Token, &'a str, usize)>, ErrorHandler) {
+        let tokens = self.tokenize();
+        (tokens, self.errors)
+    }
+
+    /// Tokens inválidos continuam na lista como `Token::Error`; cada um gera
+    /// um erro léxico e a tokenização segue a partir do próximo caractere.
+    pub fn tokenize(&mut self) -> Vec<(Token, &'a str, usize)> {
         let source = self.lexer.source();
-        self.lexer
+        let tokens: Vec<(Token, &'a str, usize)> = self.lexer
             .clone()
             .spanned()
             .map(|(res, span)| {
@@ -22,6 +34,44 @@ impl<'a> Lexer<'a> {
                 let slice = &source[span.clone()];
                 (token, slice, span.start)
             })
-            .collect()
-    } 
+            .collect();
+
+        self.errors = lexical_errors(&tokens, source);
+        tokens
+    }
+
+    pub fn errors(&self) -> &ErrorHandler {
+        &self.errors
+    }
+}
+
+/// Erros léxicos de uma lista de tokens: tokens inválidos e literais que não cabem
+/// em 32 bits. O `Parser` também os reporta, então quem usa os dois não precisa juntá-los.
+pub fn lexical_errors(tokens: &[(Token, &str, usize)], source: &str) -> ErrorHandler {
+    let mut errors = ErrorHandler::new();
+    for (token, text, offset) in tokens {
+        let span = Span::new(*offset, offset + text.len());
+        match token {
+            Token::Error if text.starts_with(|ch: char| ch.is_ascii_digit()) => errors.add_error_at(
+                ErrorCode::MalformedNumber,
+                &format!("Malformed number '{}': identifiers cannot start with a digit", text),
+                span,
+                source,
+            ),
+            Token::Error => errors.add_error_at(
+                ErrorCode::UnknownCharacter,
+                &format!("Unexpected character '{}'", text.escape_debug()),
+                span,
+                source,
+            ),
+            Token::Number if text.parse::<i32>().is_err() => errors.add_error_at(
+                ErrorCode::NumberOutOfRange,
+                &format!("Integer literal '{}' does not fit in a 32-bit integer", text),
+                span,
+                source,
+            ),
+            _ => {}
+        }
+    }
+    errors
 }
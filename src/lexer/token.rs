@@ -28,6 +28,14 @@ pub enum Token {
     #[token("for")]
     For,
 
+    // Declarações e tipos
+    #[token("let")]
+    Let,
+    #[token("int")]
+    IntType,
+    #[token("bool")]
+    BoolType,
+
     // Operadores
     #[token("+")]
     Plus,
@@ -49,6 +57,10 @@ pub enum Token {
     RBrace,
     #[token(";")]
     Semicolon,
+    #[token(":")]
+    Colon,
+    #[token("=")]
+    Assign,
 
     // Operadores lógicos
     #[token("&&")]
@@ -58,6 +70,20 @@ pub enum Token {
     #[token("!")]
     LogicalNot,
 
+    // Operadores de comparação
+    #[token("<")]
+    Less,
+    #[token(">")]
+    Greater,
+    #[token("<=")]
+    LessEqual,
+    #[token(">=")]
+    GreaterEqual,
+    #[token("==")]
+    EqualEqual,
+    #[token("!=")]
+    NotEqual,
+
     // Identificadores e números
     #[regex(r"[a-zA-Z_][a-zA-Z0-9_]*", priority = 2)]
     Identifier,
@@ -79,4 +105,32 @@ pub enum Token {
     #[default] // Token inválido (usando Default)
     #[regex(r"[0-9]+[a-zA-Z][a-zA-Z0-9_]*", priority = 3)] // variaveis que começam com numeros, exemplo: 123abc
     Error,
-}
\ No newline at end of file
+}
+
+impl Token {
+    /// Tokens que podem começar uma expressão.
+    pub fn starts_expression(&self) -> bool {
+        matches!(
+            self,
+            Token::Identifier | Token::Number | Token::LParen | Token::LogicalNot | Token::Minus
+        )
+    }
+
+    /// Tokens que começam um statement; usados como ponto de sincronização do parser.
+    pub fn starts_statement(&self) -> bool {
+        matches!(
+            self,
+            Token::MoveUp
+                | Token::MoveDown
+                | Token::MoveLeft
+                | Token::MoveRight
+                | Token::Jump
+                | Token::Attack
+                | Token::Defend
+                | Token::If
+                | Token::While
+                | Token::For
+                | Token::Let
+        )
+    }
+}
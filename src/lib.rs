@@ -1,5 +1,20 @@
+// Cada fase do compilador vive em `fase/fase.rs` (ex.: `lexer::lexer`).
+#![allow(clippy::module_inception)]
+
+pub mod span;
+pub mod json;
 pub mod lexer;
 pub mod symbol_table;
 pub mod error_handler;
 pub mod parser;
-pub mod pretty_print;
\ No newline at end of file
+pub mod cst;
+pub mod pretty_print;
+pub mod formatter;
+pub mod semantic;
+pub mod world;
+pub mod interpreter;
+pub mod bytecode;
+pub mod cfg;
+pub mod optimizer;
+pub mod dot;
+pub mod cli;
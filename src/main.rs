@@ -1,89 +1,269 @@
 use std::env;
 use std::fs;
+use std::io::IsTerminal;
 use std::process;
 use std::time::Instant;
 
-mod error_handler;
-mod lexer;
-mod parser;
-mod pretty_print;
-mod symbol_table;
-
-use lexer::lexer::Lexer;
-use parser::parser::Parser;
-use pretty_print::PrettyPrinter;
-use symbol_table::symbol_table::SymbolTable;
-use symbol_table::symbol_type::SymbolType;
+use questlang::bytecode::compiler::Compiler;
+use questlang::bytecode::disassembler::Disassembler;
+use questlang::bytecode::vm::Vm;
+use questlang::cfg::cfg::Cfg;
+use questlang::cli::{self, Command, Emit, ErrorFormat, Invocation, Options, Verbosity};
+use questlang::dot::{ast_to_dot, cfg_to_dot};
+use questlang::error_handler::error_code::ErrorCode;
+use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::error_handler::json_emitter::JsonEmitter;
+use questlang::error_handler::renderer::Renderer;
+use questlang::formatter::format_source;
+use questlang::interpreter::interpreter::Interpreter;
+use questlang::lexer::lexer::Lexer;
+use questlang::lexer::token::Token;
+use questlang::optimizer::optimizer::Optimizer;
+use questlang::parser::ast::Stmt;
+use questlang::parser::ast_json;
+use questlang::parser::parser::Parser;
+use questlang::pretty_print::PrettyPrinter;
+use questlang::semantic::analyzer::SemanticAnalyzer;
+use questlang::semantic::type_checker::TypeChecker;
+use questlang::world::map_parser::MapParser;
+use questlang::world::world::World;
 
 fn main() {
-    let start_total = Instant::now();
-    
-    let start_read = Instant::now();
-     let args: Vec<String> = env::args().collect();
-     if args.len() < 2 {
-         eprintln!("Uso: {} <arquivo_fonte>", args[0]);
-         process::exit(1);
-     }
-     let filename = &args[1];
-
-     let source_code = fs::read_to_string(filename).unwrap_or_else(|err| {
-         eprintln!("Erro ao ler o arquivo {}: {}", filename, err);
-         process::exit(1);
-     });
-     let finish_read = start_read.elapsed();
- 
-     // --- Lexical Analysis ---
-     let start_lexer = Instant::now();
-     let mut lexer = Lexer::new(&source_code);
-     let tokens = lexer.tokenize();
-     println!("--- Tokens ---");
-     for (token, text, _) in tokens.iter() {
-         println!("{:?} -> '{}'", token, text);
-     }
-     let lex_finished = start_lexer.elapsed();
- 
-     // --- Parsing ---
-     let start_parser = Instant::now();
-     let (ast, errors) = Parser::new(tokens, &source_code).parse();
-
-     if errors.has_errors() {
-         println!("\n--- Parsing Errors ---");
-         errors.report();
-     } else {
-         println!("\nParsing concluído sem erros.");
-     }
-    let parser_finished = start_parser.elapsed();
-    
-     // --- Pretty Print ---
-     let start_pretty_print = Instant::now();
-     let mut printer = PrettyPrinter::new();
-     let pretty_output = printer.print_stmts(&ast);
-     println!("\n--- Pretty Printed AST ---");
-     println!("{}", pretty_output);
-     let finish_pretty_print = start_pretty_print.elapsed();
-
-     // --- Symbol Table ---
-     let mut sym_table = SymbolTable::new();
-     if source_code.contains("hero") {
-         sym_table.insert("hero", SymbolType::Integer, 1, 1);
-     }
-     if source_code.contains("enemy") {
-         sym_table.insert("enemy", SymbolType::Boolean, 1, 1);
-     }
-     if source_code.contains("treasure") {
-         sym_table.insert("treasure", SymbolType::Integer, 1, 1);
-     }
-     if source_code.contains("trap") {
-         sym_table.insert("trap", SymbolType::Boolean, 1, 1);
-     }
-     println!("\n--- Symbol Table ---");
-     sym_table.print_table();
-
-     let finish_total = start_total.elapsed();
-     println!();
-     println!("Reading completed in {:.3} ms", finish_read.as_secs_f64() * 1e3);
-     println!("Lexing completed in {:.3} ms", lex_finished.as_secs_f64() * 1e3);
-     println!("Parsing completed in {:.3} ms", parser_finished.as_secs_f64() * 1e3);
-     println!("Pretty Printing completed in {:.3} ms", finish_pretty_print.as_secs_f64() * 1e3);
-     println!("All Code completed in {:.3} ms", finish_total.as_secs_f64() * 1e3);
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match cli::parse_args(&args) {
+        Ok(Invocation::Compile(options)) => options,
+        Ok(Invocation::Explain(code)) => explain(&code),
+        Ok(Invocation::Help) => {
+            println!("{}", cli::USAGE);
+            process::exit(0);
+        }
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let source = read_file(&options.source);
+    let session = Session::new(&options, &source);
+    let success = match options.command {
+        Command::Lex => session.finish(session.lex()),
+        Command::Parse => session.finish(session.parse()),
+        Command::Check => session.finish(session.check()),
+        Command::Fmt => session.fmt(),
+        Command::Disasm => session.finish(session.disasm()),
+        Command::Run => session.finish(session.run()),
+    };
+    process::exit(if success { 0 } else { 1 });
+}
+
+/// Estado de uma invocação: o programa lido e as opções que controlam a saída.
+/// Cada comando imprime o seu resultado em stdout e devolve os diagnósticos,
+/// que `finish` manda para stderr.
+struct Session<'a> {
+    options: &'a Options,
+    source: &'a str,
+    color: bool,
+}
+
+impl<'a> Session<'a> {
+    fn new(options: &'a Options, source: &'a str) -> Self {
+        // Cores só no terminal, e nunca com NO_COLOR definido.
+        let color = std::io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none();
+        Session { options, source, color }
+    }
+
+    fn lex(&self) -> ErrorHandler {
+        let (tokens, errors) = self.tokenize();
+        if self.options.verbosity > Verbosity::Quiet {
+            for (token, text, _) in &tokens {
+                println!("{:?} -> '{}'", token, text);
+            }
+        }
+        errors
+    }
+
+    fn parse(&self) -> ErrorHandler {
+        let (ast, mut errors) = self.parse_source();
+        let ast = self.optimize(ast, &mut errors);
+        if self.options.verbosity > Verbosity::Quiet {
+            match self.options.emit {
+                Emit::Ast => println!("{}", PrettyPrinter::new().print_stmts(&ast)),
+                Emit::AstJson => println!("{}", ast_json::to_json(&ast)),
+                Emit::AstDot => print!("{}", ast_to_dot(&ast)),
+                Emit::CfgDot => print!("{}", cfg_to_dot(&Cfg::build(&ast))),
+            }
+        }
+        errors
+    }
+
+    fn check(&self) -> ErrorHandler {
+        let (ast, mut diagnostics) = self.analyze();
+        self.optimize(ast, &mut diagnostics);
+        diagnostics
+    }
+
+    /// Com `--check`, falha se o arquivo não estiver no formato canônico.
+    fn fmt(&self) -> bool {
+        let formatted = match self.timed("Formatting", || format_source(self.source)) {
+            Ok(formatted) => formatted,
+            Err(errors) => return self.finish(errors),
+        };
+        if !self.options.check {
+            if self.options.verbosity > Verbosity::Quiet {
+                print!("{}", formatted);
+            }
+            return true;
+        }
+        if formatted == self.source {
+            return true;
+        }
+        if self.options.verbosity > Verbosity::Quiet {
+            let line = first_difference(self.source, &formatted);
+            eprintln!("{}:{}: arquivo não está formatado", self.options.source, line);
+        }
+        false
+    }
+
+    fn disasm(&self) -> ErrorHandler {
+        let (ast, mut errors) = self.parse_source();
+        let ast = self.optimize(ast, &mut errors);
+        if !errors.has_errors() && self.options.verbosity > Verbosity::Quiet {
+            let chunk = self.timed("Compilation", || Compiler::new().compile(&ast));
+            print!("{}", Disassembler::new(&chunk).with_source(self.source).disassemble());
+        }
+        errors
+    }
+
+    /// Executa só programas que passaram por todas as análises.
+    fn run(&self) -> ErrorHandler {
+        let world = self.load_world();
+        let (ast, mut diagnostics) = self.analyze();
+        if diagnostics.has_errors() {
+            return diagnostics;
+        }
+        let ast = self.optimize(ast, &mut diagnostics);
+        let (trace, runtime_errors) = if self.options.vm {
+            let chunk = self.timed("Compilation", || Compiler::new().compile(&ast));
+            self.timed("Execution", || Vm::new(world).with_source(self.source).run(&chunk))
+        } else {
+            self.timed("Execution", || Interpreter::new(world).with_source(self.source).run(&ast))
+        };
+        if self.options.verbosity > Verbosity::Quiet {
+            for event in &trace.events {
+                println!("{}", event);
+            }
+        }
+        diagnostics.extend(runtime_errors);
+        diagnostics
+    }
+
+    fn tokenize(&self) -> (Vec<(Token, &'a str, usize)>, ErrorHandler) {
+        self.timed("Lexing", || Lexer::new(self.source).lex())
+    }
+
+    fn parse_source(&self) -> (Vec<Stmt>, ErrorHandler) {
+        // O parser já reporta os erros léxicos dos tokens.
+        let tokens = self.timed("Lexing", || Lexer::new(self.source).tokenize());
+        self.timed("Parsing", || Parser::new(tokens, self.source).parse())
+    }
+
+    /// Análise semântica e de tipos sobre a AST, mesmo que parcial.
+    fn analyze(&self) -> (Vec<Stmt>, ErrorHandler) {
+        let (ast, mut diagnostics) = self.parse_source();
+        let (symbols, semantic_errors) = self.timed("Semantic Analysis", || {
+            let (symbols, mut errors) = SemanticAnalyzer::new(self.source).analyze(&ast);
+            errors.extend(TypeChecker::new(self.source).check(&ast));
+            (symbols, errors)
+        });
+        if self.options.verbosity == Verbosity::Verbose {
+            symbols.print_table();
+        }
+        diagnostics.extend(semantic_errors);
+        (ast, diagnostics)
+    }
+
+    /// Com `--optimize`, simplifica programas sem erros; cada reescrita vira um warning.
+    fn optimize(&self, ast: Vec<Stmt>, diagnostics: &mut ErrorHandler) -> Vec<Stmt> {
+        if !self.options.optimize || diagnostics.has_errors() {
+            return ast;
+        }
+        let (ast, warnings) = self.timed("Optimization", || Optimizer::new(self.source).optimize(ast));
+        diagnostics.extend(warnings);
+        ast
+    }
+
+    /// Carrega o mapa de `run`; erros no mapa encerram o programa.
+    fn load_world(&self) -> World {
+        let Some(map_file) = &self.options.map else {
+            return World::default();
+        };
+        let map_source = read_file(map_file);
+        let (world, map_errors) = MapParser::new(&map_source).parse();
+        world.unwrap_or_else(|| {
+            self.emit(&map_errors, &map_source, map_file);
+            process::exit(1);
+        })
+    }
+
+    /// Emite os diagnósticos do programa; o comando dá certo se não houve erros.
+    fn finish(&self, diagnostics: ErrorHandler) -> bool {
+        self.emit(&diagnostics, self.source, &self.options.source);
+        !diagnostics.has_errors()
+    }
+
+    fn emit(&self, diagnostics: &ErrorHandler, source: &str, file: &str) {
+        match self.options.error_format {
+            ErrorFormat::Json => eprint!("{}", JsonEmitter::new(source).with_file(file).emit(diagnostics)),
+            ErrorFormat::Human => {
+                let renderer = Renderer::new(source).with_file(file).with_color(self.color);
+                eprint!("{}", diagnostics.render(&renderer));
+            }
+        }
+    }
+
+    /// Roda uma fase e, no modo verboso, mostra quanto tempo ela levou.
+    fn timed<T>(&self, phase: &str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let result = f();
+        if self.options.verbosity == Verbosity::Verbose {
+            eprintln!("{} completed in {:.3} ms", phase, start.elapsed().as_secs_f64() * 1e3);
+        }
+        result
+    }
+}
+
+/// Primeira linha (a partir de 1) em que o arquivo difere da versão formatada.
+fn first_difference(source: &str, formatted: &str) -> usize {
+    let mut original = source.lines();
+    let mut expected = formatted.lines();
+    let mut line = 1;
+    while let (Some(a), Some(b)) = (original.next(), expected.next()) {
+        if a != b {
+            break;
+        }
+        line += 1;
+    }
+    line
+}
+
+fn read_file(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("Erro ao ler o arquivo {}: {}", path, err);
+        process::exit(2);
+    })
+}
+
+/// Mostra a explicação longa de um código de erro (`--explain Q0001`) e encerra.
+fn explain(code: &str) -> ! {
+    match ErrorCode::parse(code) {
+        Some(code) => {
+            println!("{} ({:?})\n", code, code.error_type());
+            println!("{}", code.explanation());
+            process::exit(0);
+        }
+        None => {
+            eprintln!("Código de erro desconhecido: '{}'", code);
+            process::exit(2);
+        }
+    }
 }
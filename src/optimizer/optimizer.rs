@@ -0,0 +1,173 @@
+use crate::cfg::reachability::{constant_condition, constant_value};
+use crate::error_handler::{error_code::ErrorCode, error_handler::ErrorHandler};
+use crate::formatter::format_expr;
+use crate::interpreter::value::Value;
+use crate::parser::ast::{BinOp, Expr, Span, Stmt};
+use crate::parser::visitor::{fold_expr, Fold};
+
+/// Simplifica a AST sem mudar o que o programa faz, com um warning para cada reescrita:
+///
+/// - contas só com números viram um número (`10 - 5` vira `5`), e constantes somadas
+///   ao mesmo valor se juntam (`hero + 1 + 2` vira `hero + 3`);
+/// - um `if` com condição constante fica só com o ramo que executa;
+/// - um `while` ou `for` cuja condição é sempre falsa é removido.
+///
+/// Contas que falhariam ao executar, como divisão por zero ou overflow, ficam como
+/// estão para que o erro continue aparecendo no mesmo ponto. Um ramo que declara
+/// variáveis não é trazido para o bloco de fora, pois mudaria o escopo delas.
+pub struct Optimizer<'a> {
+    source: &'a str,
+    warnings: ErrorHandler,
+}
+
+impl<'a> Optimizer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Optimizer {
+            source,
+            warnings: ErrorHandler::new(),
+        }
+    }
+
+    pub fn optimize(mut self, stmts: Vec<Stmt>) -> (Vec<Stmt>, ErrorHandler) {
+        let stmts = self.fold_stmts(stmts);
+        (stmts, self.warnings)
+    }
+
+    /// Reescreve um statement já com os filhos otimizados; pode virar nenhum ou vários.
+    fn eliminate(&mut self, stmt: Stmt, out: &mut Vec<Stmt>) {
+        match stmt {
+            Stmt::IfStmt { condition, then_branch, else_branch, span } => {
+                let Some(value) = constant_condition(&condition) else {
+                    out.push(Stmt::IfStmt { condition, then_branch, else_branch, span });
+                    return;
+                };
+                let (live, dead) = if value { (then_branch, else_branch) } else { (else_branch, then_branch) };
+                if declares_variables(&live) {
+                    // O `if` continua, só sem o ramo que nunca executa.
+                    if !dead.is_empty() {
+                        let msg = format!("Condition is always {}; the branch that never runs was removed", value);
+                        self.warn(ErrorCode::ConstantCondition, &msg, condition.span());
+                    }
+                    let (then_branch, else_branch) = if value { (live, Vec::new()) } else { (Vec::new(), live) };
+                    out.push(Stmt::IfStmt { condition, then_branch, else_branch, span });
+                    return;
+                }
+                let msg = match (value, live.is_empty()) {
+                    (true, _) => "Condition is always true; the 'if' was replaced by its body",
+                    (false, false) => "Condition is always false; the 'if' was replaced by its 'else' branch",
+                    (false, true) => "Condition is always false; the 'if' was removed",
+                };
+                self.warn(ErrorCode::ConstantCondition, msg, condition.span());
+                out.extend(live);
+            }
+            Stmt::WhileStmt { condition, .. } if constant_condition(&condition) == Some(false) => {
+                self.warn(ErrorCode::LoopNeverRuns, "Loop condition is always false; the 'while' loop was removed", condition.span());
+            }
+            Stmt::ForStmt { init, condition, .. } if constant_condition(&condition) == Some(false) => {
+                self.warn(ErrorCode::LoopNeverRuns, "Loop condition is always false; the 'for' loop was removed", condition.span());
+                // O `init` ainda executa uma vez. Um `let` some com o escopo do laço,
+                // mas o valor é avaliado se puder falhar.
+                match *init {
+                    Stmt::LetStmt { value: Expr::Number(..) | Expr::Identifier(..), .. } => {}
+                    Stmt::LetStmt { value, .. } => out.push(Stmt::ExprStmt(value)),
+                    init => out.push(init),
+                }
+            }
+            stmt => out.push(stmt),
+        }
+    }
+
+    fn combine_constants(&mut self, expr: Expr) -> Expr {
+        let Some(combined) = reassociate(&expr) else {
+            return expr;
+        };
+        let msg = format!("Constants in '{}' were combined into '{}'", format_expr(&expr), format_expr(&combined));
+        self.warn(ErrorCode::ConstantsCombined, &msg, expr.span());
+        combined
+    }
+
+    fn warn(&mut self, code: ErrorCode, msg: &str, span: Span) {
+        self.warnings.add_coded_warning_at(code, msg, span, self.source);
+    }
+}
+
+impl Fold for Optimizer<'_> {
+    fn fold_stmts(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        let mut out = Vec::new();
+        for stmt in stmts {
+            let stmt = self.fold_stmt(stmt);
+            self.eliminate(stmt, &mut out);
+        }
+        out
+    }
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        if let Some(n) = constant_int(&expr) {
+            let text = format_expr(&expr);
+            // Um literal negativo como `-5` já é a forma mais simples.
+            if text != n.to_string() {
+                let msg = format!("Constant expression '{}' was folded to {}", text, n);
+                self.warn(ErrorCode::ConstantFolded, &msg, expr.span());
+            }
+            return Expr::Number(n, expr.span());
+        }
+        let expr = fold_expr(self, expr);
+        self.combine_constants(expr)
+    }
+}
+
+/// Valor de uma conta só com números. Expressões booleanas constantes, como `3 > 2`,
+/// ficam como estão: a AST não tem literal booleano, e como condição elas já são
+/// resolvidas por `constant_condition`.
+fn constant_int(expr: &Expr) -> Option<i32> {
+    match constant_value(expr)? {
+        Value::Int(n) => Some(n),
+        Value::Bool(_) => None,
+    }
+}
+
+/// `(e + a) + b` vira `e + (a + b)` quando `a` e `b` empurram o valor para o mesmo
+/// lado: assim a conta original estoura exatamente quando a nova estoura.
+fn reassociate(expr: &Expr) -> Option<Expr> {
+    let Expr::BinaryOp { left, op: outer, op_span, right, span } = expr else {
+        return None;
+    };
+    let Expr::Number(b, _) = **right else {
+        return None;
+    };
+    let Expr::BinaryOp { left: operand, op: inner, right: inner_right, .. } = &**left else {
+        return None;
+    };
+    let Expr::Number(a, _) = **inner_right else {
+        return None;
+    };
+    let (a, b) = (signed(inner, a)?, signed(outer, b)?);
+    if (a >= 0) != (b >= 0) {
+        return None;
+    }
+    let total = a.checked_add(b)?;
+    let (op, n) = match total.checked_neg() {
+        Some(abs) if total < 0 => (BinOp::Sub, abs),
+        _ => (BinOp::Add, total),
+    };
+    Some(Expr::BinaryOp {
+        left: operand.clone(),
+        op,
+        op_span: *op_span,
+        right: Box::new(Expr::Number(n, *span)),
+        span: *span,
+    })
+}
+
+/// Quanto `+ n` ou `- n` soma ao valor.
+fn signed(op: &BinOp, n: i32) -> Option<i32> {
+    match op {
+        BinOp::Add => Some(n),
+        BinOp::Sub => n.checked_neg(),
+        _ => None,
+    }
+}
+
+fn declares_variables(stmts: &[Stmt]) -> bool {
+    stmts.iter().any(|stmt| matches!(stmt, Stmt::LetStmt { .. }))
+}
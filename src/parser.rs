@@ -1,6 +1,8 @@
 pub mod ast;
 pub mod ast_builder;
+pub mod ast_json;
 pub mod parser;
 pub mod statements;
 pub mod expressions;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+pub mod visitor;
\ No newline at end of file
@@ -1,3 +1,9 @@
+use std::fmt;
+
+use crate::symbol_table::symbol_type::SymbolType;
+
+pub use crate::span::Span;
+
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Command {
@@ -20,43 +26,240 @@ pub enum ActionCommand {
     Defend,
 }
 
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keyword = match self {
+            Command::Move(MoveCommand::MoveUp) => "move_up",
+            Command::Move(MoveCommand::MoveDown) => "move_down",
+            Command::Move(MoveCommand::MoveLeft) => "move_left",
+            Command::Move(MoveCommand::MoveRight) => "move_right",
+            Command::Action(ActionCommand::Jump) => "jump",
+            Command::Action(ActionCommand::Attack) => "attack",
+            Command::Action(ActionCommand::Defend) => "defend",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+impl Command {
+    pub const ALL: [Command; 7] = [
+        Command::Move(MoveCommand::MoveUp),
+        Command::Move(MoveCommand::MoveDown),
+        Command::Move(MoveCommand::MoveLeft),
+        Command::Move(MoveCommand::MoveRight),
+        Command::Action(ActionCommand::Jump),
+        Command::Action(ActionCommand::Attack),
+        Command::Action(ActionCommand::Defend),
+    ];
+
+    /// Comando a partir da palavra-chave (`move_up`, `jump`, ...).
+    pub fn from_keyword(keyword: &str) -> Option<Command> {
+        Command::ALL.into_iter().find(|command| command.to_string() == keyword)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Stmt {
-    Command(Command),
+    Command(Command, Span),
 
     IfStmt {
         condition: Expr,
         then_branch: Vec<Stmt>,
         else_branch: Vec<Stmt>,
+        span: Span,
     },
 
     WhileStmt {
         condition: Expr,
         body: Vec<Stmt>,
+        span: Span,
     },
 
     ForStmt {
-        init: Expr,
+        init: Box<Stmt>,
         condition: Expr,
-        update: Expr,
+        update: Box<Stmt>,
         body: Vec<Stmt>,
+        span: Span,
     },
+
+    /// `name_span` cobre apenas o nome da variável.
+    LetStmt {
+        name: String,
+        name_span: Span,
+        var_type: SymbolType,
+        value: Expr,
+        span: Span,
+    },
+
+    AssignStmt {
+        name: String,
+        name_span: Span,
+        value: Expr,
+        span: Span,
+    },
+    /// O span é o da própria expressão.
     ExprStmt(Expr),
+
+    /// Trecho que não pôde ser analisado; o erro sintático já foi reportado.
+    Error(Span),
+}
+
+impl Stmt {
+    pub fn span(&self) -> Span {
+        match self {
+            Stmt::Command(_, span)
+            | Stmt::IfStmt { span, .. }
+            | Stmt::WhileStmt { span, .. }
+            | Stmt::ForStmt { span, .. }
+            | Stmt::LetStmt { span, .. }
+            | Stmt::AssignStmt { span, .. }
+            | Stmt::Error(span) => *span,
+            Stmt::ExprStmt(expr) => expr.span(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum BinOp {
     Add,
     Sub,
+    Mul,
+    Div,
+    And,
+    Or,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl BinOp {
+    /// Nível de precedência; quanto maior, mais forte a ligação.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinOp::Or => 1,
+            BinOp::And => 2,
+            BinOp::Eq | BinOp::Ne => 3,
+            BinOp::Lt | BinOp::Gt | BinOp::Le | BinOp::Ge => 4,
+            BinOp::Add | BinOp::Sub => 5,
+            BinOp::Mul | BinOp::Div => 6,
+        }
+    }
+
+    /// Operadores cujo resultado é Boolean independentemente dos operandos.
+    pub fn is_comparison(&self) -> bool {
+        matches!(self, BinOp::Lt | BinOp::Gt | BinOp::Le | BinOp::Ge | BinOp::Eq | BinOp::Ne)
+    }
+
+    /// Operador a partir do símbolo (`+`, `&&`, ...).
+    pub fn from_symbol(symbol: &str) -> Option<BinOp> {
+        let op = match symbol {
+            "+" => BinOp::Add,
+            "-" => BinOp::Sub,
+            "*" => BinOp::Mul,
+            "/" => BinOp::Div,
+            "&&" => BinOp::And,
+            "||" => BinOp::Or,
+            "<" => BinOp::Lt,
+            ">" => BinOp::Gt,
+            "<=" => BinOp::Le,
+            ">=" => BinOp::Ge,
+            "==" => BinOp::Eq,
+            "!=" => BinOp::Ne,
+            _ => return None,
+        };
+        Some(op)
+    }
+}
+
+impl fmt::Display for BinOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+            BinOp::And => "&&",
+            BinOp::Or => "||",
+            BinOp::Lt => "<",
+            BinOp::Gt => ">",
+            BinOp::Le => "<=",
+            BinOp::Ge => ">=",
+            BinOp::Eq => "==",
+            BinOp::Ne => "!=",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnaryOp {
+    Not,
+    Neg,
+}
+
+impl UnaryOp {
+    pub fn from_symbol(symbol: &str) -> Option<UnaryOp> {
+        match symbol {
+            "!" => Some(UnaryOp::Not),
+            "-" => Some(UnaryOp::Neg),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            UnaryOp::Not => "!",
+            UnaryOp::Neg => "-",
+        };
+        write!(f, "{}", symbol)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
-    Identifier(String),
-    Number(i32),
+    Identifier(String, Span),
+    Number(i32, Span),
+    /// `op_span` cobre apenas o operador.
     BinaryOp {
         left: Box<Expr>,
         op: BinOp,
+        op_span: Span,
         right: Box<Expr>,
+        span: Span,
     },
+    UnaryOp {
+        op: UnaryOp,
+        operand: Box<Expr>,
+        span: Span,
+    },
+    /// Expressão que não pôde ser analisada; o erro já foi reportado.
+    Error(Span),
+}
+
+impl Expr {
+    /// Precedência do nó: operadores unários ficam acima de todos os binários
+    /// e identificadores/números nunca precisam de parênteses.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Expr::BinaryOp { op, .. } => op.precedence(),
+            Expr::UnaryOp { .. } => 7,
+            Expr::Identifier(..) | Expr::Number(..) | Expr::Error(_) => 8,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            Expr::Identifier(_, span)
+            | Expr::Number(_, span)
+            | Expr::BinaryOp { span, .. }
+            | Expr::UnaryOp { span, .. }
+            | Expr::Error(span) => *span,
+        }
+    }
 }
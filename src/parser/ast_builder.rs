@@ -1,4 +1,5 @@
-use crate::parser::ast::{Stmt, Command, Expr, BinOp};
+use crate::parser::ast::{Stmt, Command, Expr, BinOp, UnaryOp, Span};
+use crate::symbol_table::symbol_type::SymbolType;
 
 #[derive(Default)]
 pub struct ASTBuilder;
@@ -8,28 +9,49 @@ impl ASTBuilder {
         ASTBuilder
     }
 
-    pub fn new_command(&self, command: Command) -> Stmt {
-        Stmt::Command(command)
+    pub fn new_command(&self, command: Command, span: Span) -> Stmt {
+        Stmt::Command(command, span)
     }
 
-    pub fn new_if(&self, condition: Expr, then_branch: Vec<Stmt>, else_branch: Vec<Stmt>) -> Stmt {
+    pub fn new_if(&self, condition: Expr, then_branch: Vec<Stmt>, else_branch: Vec<Stmt>, span: Span) -> Stmt {
         Stmt::IfStmt {
             condition,
             then_branch,
             else_branch,
+            span,
         }
     }
 
-    pub fn new_while(&self, condition: Expr, body: Vec<Stmt>) -> Stmt {
-        Stmt::WhileStmt { condition, body }
+    pub fn new_while(&self, condition: Expr, body: Vec<Stmt>, span: Span) -> Stmt {
+        Stmt::WhileStmt { condition, body, span }
     }
 
-    pub fn new_for(&self, init: Expr, condition: Expr, update: Expr, body: Vec<Stmt>) -> Stmt {
+    pub fn new_for(&self, init: Stmt, condition: Expr, update: Stmt, body: Vec<Stmt>, span: Span) -> Stmt {
         Stmt::ForStmt {
-            init,
+            init: Box::new(init),
             condition,
-            update,
+            update: Box::new(update),
             body,
+            span,
+        }
+    }
+
+    pub fn new_let(&self, name: &str, name_span: Span, var_type: SymbolType, value: Expr, span: Span) -> Stmt {
+        Stmt::LetStmt {
+            name: name.to_string(),
+            name_span,
+            var_type,
+            value,
+            span,
+        }
+    }
+
+    pub fn new_assign(&self, name: &str, name_span: Span, value: Expr, span: Span) -> Stmt {
+        Stmt::AssignStmt {
+            name: name.to_string(),
+            name_span,
+            value,
+            span,
         }
     }
 
@@ -37,19 +59,37 @@ impl ASTBuilder {
         Stmt::ExprStmt(expr)
     }
 
-    pub fn new_identifier(&self, name: &str) -> Expr {
-        Expr::Identifier(name.to_string())
+    pub fn new_error_stmt(&self, span: Span) -> Stmt {
+        Stmt::Error(span)
+    }
+
+    pub fn new_identifier(&self, name: &str, span: Span) -> Expr {
+        Expr::Identifier(name.to_string(), span)
     }
 
-    pub fn new_number(&self, value: i32) -> Expr {
-        Expr::Number(value)
+    pub fn new_number(&self, value: i32, span: Span) -> Expr {
+        Expr::Number(value, span)
     }
     
-    pub fn new_binop(&self, left: Expr, op: BinOp, right: Expr) -> Expr {
+    pub fn new_binop(&self, left: Expr, op: BinOp, op_span: Span, right: Expr, span: Span) -> Expr {
         Expr::BinaryOp {
             left: Box::new(left),
             op,
+            op_span,
             right: Box::new(right),
+            span,
         }
     }
+
+    pub fn new_unary(&self, op: UnaryOp, operand: Expr, span: Span) -> Expr {
+        Expr::UnaryOp {
+            op,
+            operand: Box::new(operand),
+            span,
+        }
+    }
+
+    pub fn new_error_expr(&self, span: Span) -> Expr {
+        Expr::Error(span)
+    }
 }
@@ -0,0 +1,266 @@
+use crate::json::Json;
+use crate::parser::ast::{BinOp, Command, Expr, Span, Stmt, UnaryOp};
+use crate::symbol_table::symbol_type::SymbolType;
+
+/// Versão do formato; muda quando um campo deixa de ser compatível.
+pub const AST_JSON_VERSION: i64 = 1;
+
+/// Codifica o programa em JSON, num formato estável lido de volta por `from_json`:
+///
+/// ```text
+/// {"version":1,"statements":[
+///   {"kind":"while","condition":{"kind":"identifier","name":"enemy","span":{"start":7,"end":12}},
+///    "body":[{"kind":"command","command":"attack","span":{"start":16,"end":22}}],
+///    "span":{"start":0,"end":24}}]}
+/// ```
+///
+/// Cada nó tem um `kind` e os filhos em campos com nome. Operadores e comandos usam
+/// a grafia do código-fonte. O `span` é omitido quando o nó não tem posição
+/// (`Span::default()`), como numa AST montada à mão; o mesmo vale para o
+/// `name_span` de `let`/atribuição e para o `op_span` dos operadores binários.
+pub fn to_json(stmts: &[Stmt]) -> Json {
+    Json::object(vec![
+        ("version", Json::Number(AST_JSON_VERSION)),
+        ("statements", stmts_to_json(stmts)),
+    ])
+}
+
+fn stmts_to_json(stmts: &[Stmt]) -> Json {
+    Json::Array(stmts.iter().map(stmt_to_json).collect())
+}
+
+fn stmt_to_json(stmt: &Stmt) -> Json {
+    let (kind, mut fields) = match stmt {
+        Stmt::Command(command, _) => ("command", vec![("command", Json::string(&command.to_string()))]),
+        Stmt::IfStmt { condition, then_branch, else_branch, .. } => (
+            "if",
+            vec![
+                ("condition", expr_to_json(condition)),
+                ("then", stmts_to_json(then_branch)),
+                ("else", stmts_to_json(else_branch)),
+            ],
+        ),
+        Stmt::WhileStmt { condition, body, .. } => {
+            ("while", vec![("condition", expr_to_json(condition)), ("body", stmts_to_json(body))])
+        }
+        Stmt::ForStmt { init, condition, update, body, .. } => (
+            "for",
+            vec![
+                ("init", stmt_to_json(init)),
+                ("condition", expr_to_json(condition)),
+                ("update", stmt_to_json(update)),
+                ("body", stmts_to_json(body)),
+            ],
+        ),
+        Stmt::LetStmt { name, name_span, var_type, value, .. } => {
+            let mut fields = vec![("name", Json::string(name))];
+            push_span(&mut fields, "name_span", *name_span);
+            fields.push(("type", Json::string(var_type.keyword())));
+            fields.push(("value", expr_to_json(value)));
+            ("let", fields)
+        }
+        Stmt::AssignStmt { name, name_span, value, .. } => {
+            let mut fields = vec![("name", Json::string(name))];
+            push_span(&mut fields, "name_span", *name_span);
+            fields.push(("value", expr_to_json(value)));
+            ("assign", fields)
+        }
+        // O span de um statement de expressão é o da própria expressão.
+        Stmt::ExprStmt(expr) => return Json::object(vec![("kind", Json::string("expr")), ("expr", expr_to_json(expr))]),
+        Stmt::Error(_) => ("error", vec![]),
+    };
+    fields.insert(0, ("kind", Json::string(kind)));
+    node(fields, stmt.span())
+}
+
+fn expr_to_json(expr: &Expr) -> Json {
+    let (kind, mut fields) = match expr {
+        Expr::Identifier(name, _) => ("identifier", vec![("name", Json::string(name))]),
+        Expr::Number(n, _) => ("number", vec![("value", Json::Number(*n as i64))]),
+        Expr::BinaryOp { left, op, op_span, right, .. } => {
+            let mut fields = vec![("op", Json::string(&op.to_string()))];
+            push_span(&mut fields, "op_span", *op_span);
+            fields.push(("left", expr_to_json(left)));
+            fields.push(("right", expr_to_json(right)));
+            ("binary", fields)
+        }
+        Expr::UnaryOp { op, operand, .. } => {
+            ("unary", vec![("op", Json::string(&op.to_string())), ("operand", expr_to_json(operand))])
+        }
+        Expr::Error(_) => ("error", vec![]),
+    };
+    fields.insert(0, ("kind", Json::string(kind)));
+    node(fields, expr.span())
+}
+
+fn node(mut fields: Vec<(&str, Json)>, span: Span) -> Json {
+    push_span(&mut fields, "span", span);
+    Json::object(fields)
+}
+
+fn push_span<'k>(fields: &mut Vec<(&'k str, Json)>, key: &'k str, span: Span) {
+    if span != Span::default() {
+        let span = Json::object(vec![
+            ("start", Json::Number(span.start as i64)),
+            ("end", Json::Number(span.end as i64)),
+        ]);
+        fields.push((key, span));
+    }
+}
+
+/// Lê de volta o que `to_json` escreveu. Campos desconhecidos são ignorados; os
+/// erros dizem onde está o problema, como em `$.statements[2].condition: missing field 'kind'`.
+pub fn from_json(json: &Json) -> Result<Vec<Stmt>, String> {
+    let version = field(json, "version", "$")?;
+    if version.as_i64() != Some(AST_JSON_VERSION) {
+        return Err(format!("$.version: unsupported version {} (expected {})", describe(version), AST_JSON_VERSION));
+    }
+    stmts_from_json(field(json, "statements", "$")?, "$.statements")
+}
+
+/// `from_json` a partir do texto.
+pub fn from_json_str(text: &str) -> Result<Vec<Stmt>, String> {
+    from_json(&Json::parse(text)?)
+}
+
+fn stmts_from_json(json: &Json, path: &str) -> Result<Vec<Stmt>, String> {
+    let items = json.as_array().ok_or_else(|| format!("{}: expected an array of statements", path))?;
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| stmt_from_json(item, &format!("{}[{}]", path, i)))
+        .collect()
+}
+
+fn stmt_from_json(json: &Json, path: &str) -> Result<Stmt, String> {
+    let child = |key: &str| format!("{}.{}", path, key);
+    let stmt = match string_field(json, "kind", path)? {
+        "command" => {
+            let keyword = string_field(json, "command", path)?;
+            let command = Command::from_keyword(keyword).ok_or_else(|| format!("{}: unknown command '{}'", child("command"), keyword))?;
+            Stmt::Command(command, span_from_json(json, path)?)
+        }
+        "if" => Stmt::IfStmt {
+            condition: expr_from_json(field(json, "condition", path)?, &child("condition"))?,
+            then_branch: stmts_from_json(field(json, "then", path)?, &child("then"))?,
+            else_branch: stmts_from_json(field(json, "else", path)?, &child("else"))?,
+            span: span_from_json(json, path)?,
+        },
+        "while" => Stmt::WhileStmt {
+            condition: expr_from_json(field(json, "condition", path)?, &child("condition"))?,
+            body: stmts_from_json(field(json, "body", path)?, &child("body"))?,
+            span: span_from_json(json, path)?,
+        },
+        "for" => Stmt::ForStmt {
+            init: Box::new(stmt_from_json(field(json, "init", path)?, &child("init"))?),
+            condition: expr_from_json(field(json, "condition", path)?, &child("condition"))?,
+            update: Box::new(stmt_from_json(field(json, "update", path)?, &child("update"))?),
+            body: stmts_from_json(field(json, "body", path)?, &child("body"))?,
+            span: span_from_json(json, path)?,
+        },
+        "let" => {
+            let keyword = string_field(json, "type", path)?;
+            let var_type = SymbolType::from_keyword(keyword).ok_or_else(|| format!("{}: unknown type '{}'", child("type"), keyword))?;
+            Stmt::LetStmt {
+                name: string_field(json, "name", path)?.to_string(),
+                name_span: named_span_from_json(json, "name_span", path)?,
+                var_type,
+                value: expr_from_json(field(json, "value", path)?, &child("value"))?,
+                span: span_from_json(json, path)?,
+            }
+        }
+        "assign" => Stmt::AssignStmt {
+            name: string_field(json, "name", path)?.to_string(),
+            name_span: named_span_from_json(json, "name_span", path)?,
+            value: expr_from_json(field(json, "value", path)?, &child("value"))?,
+            span: span_from_json(json, path)?,
+        },
+        "expr" => Stmt::ExprStmt(expr_from_json(field(json, "expr", path)?, &child("expr"))?),
+        "error" => Stmt::Error(span_from_json(json, path)?),
+        other => return Err(format!("{}: unknown statement kind '{}'", path, other)),
+    };
+    Ok(stmt)
+}
+
+fn expr_from_json(json: &Json, path: &str) -> Result<Expr, String> {
+    let child = |key: &str| format!("{}.{}", path, key);
+    let expr = match string_field(json, "kind", path)? {
+        "identifier" => Expr::Identifier(string_field(json, "name", path)?.to_string(), span_from_json(json, path)?),
+        "number" => {
+            let value = field(json, "value", path)?;
+            let n = value
+                .as_i64()
+                .and_then(|n| i32::try_from(n).ok())
+                .ok_or_else(|| format!("{}: expected a 32-bit integer, found {}", child("value"), describe(value)))?;
+            Expr::Number(n, span_from_json(json, path)?)
+        }
+        "binary" => {
+            let symbol = string_field(json, "op", path)?;
+            let op = BinOp::from_symbol(symbol).ok_or_else(|| format!("{}: unknown binary operator '{}'", child("op"), symbol))?;
+            Expr::BinaryOp {
+                left: Box::new(expr_from_json(field(json, "left", path)?, &child("left"))?),
+                op,
+                op_span: named_span_from_json(json, "op_span", path)?,
+                right: Box::new(expr_from_json(field(json, "right", path)?, &child("right"))?),
+                span: span_from_json(json, path)?,
+            }
+        }
+        "unary" => {
+            let symbol = string_field(json, "op", path)?;
+            let op = UnaryOp::from_symbol(symbol).ok_or_else(|| format!("{}: unknown unary operator '{}'", child("op"), symbol))?;
+            Expr::UnaryOp {
+                op,
+                operand: Box::new(expr_from_json(field(json, "operand", path)?, &child("operand"))?),
+                span: span_from_json(json, path)?,
+            }
+        }
+        "error" => Expr::Error(span_from_json(json, path)?),
+        other => return Err(format!("{}: unknown expression kind '{}'", path, other)),
+    };
+    Ok(expr)
+}
+
+/// Sem o campo `span`, o nó fica sem posição.
+fn span_from_json(json: &Json, path: &str) -> Result<Span, String> {
+    named_span_from_json(json, "span", path)
+}
+
+fn named_span_from_json(json: &Json, key: &str, path: &str) -> Result<Span, String> {
+    let Some(span) = json.get(key) else {
+        return Ok(Span::default());
+    };
+    let path = format!("{}.{}", path, key);
+    let offset = |key: &str| {
+        let value = field(span, key, &path)?;
+        value
+            .as_i64()
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| format!("{}.{}: expected a non-negative offset, found {}", path, key, describe(value)))
+    };
+    let (start, end) = (offset("start")?, offset("end")?);
+    if start > end {
+        return Err(format!("{}: start {} is after end {}", path, start, end));
+    }
+    Ok(Span::new(start, end))
+}
+
+fn field<'j>(json: &'j Json, key: &str, path: &str) -> Result<&'j Json, String> {
+    match json {
+        Json::Object(_) => json.get(key).ok_or_else(|| format!("{}: missing field '{}'", path, key)),
+        other => Err(format!("{}: expected an object, found {}", path, describe(other))),
+    }
+}
+
+fn string_field<'j>(json: &'j Json, key: &str, path: &str) -> Result<&'j str, String> {
+    let value = field(json, key, path)?;
+    value.as_str().ok_or_else(|| format!("{}.{}: expected a string, found {}", path, key, describe(value)))
+}
+
+/// Valores compostos aparecem só pelo tipo, para não repetir um nó inteiro na mensagem.
+fn describe(json: &Json) -> String {
+    match json {
+        Json::Array(_) => "an array".to_string(),
+        Json::Object(_) => "an object".to_string(),
+        other => other.to_string(),
+    }
+}
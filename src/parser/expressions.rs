@@ -1,45 +1,162 @@
 use crate::{
-    parser::ast::{Expr, BinOp},
+    parser::ast::{Expr, BinOp, UnaryOp, Span},
+    error_handler::error_code::ErrorCode,
     lexer::token::Token,
 };
 
 use super::parser::Parser;
 
+// Precedência, da menor para a maior:
+//   ||  →  &&  →  == !=  →  < > <= >=  →  + -  →  * /  →  ! - (unários)  →  primários
 impl<'a> Parser<'a> {
 
     pub fn parse_expr(&mut self) -> Option<Expr> {
-        let mut expr = self.parse_primary()?;
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let mut expr = self.parse_and()?;
+
+        while self.check(Token::LogicalOr) {
+            let op_start = self.current_offset();
+            self.advance();
+            let op_span = self.span_from(op_start);
+            let right = self.parse_and()?;
+            expr = self.builder.new_binop(expr, BinOp::Or, op_span, right, self.span_from(start));
+        }
+        Some(expr)
+    }
+
+    fn parse_and(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let mut expr = self.parse_equality()?;
+
+        while self.check(Token::LogicalAnd) {
+            let op_start = self.current_offset();
+            self.advance();
+            let op_span = self.span_from(op_start);
+            let right = self.parse_equality()?;
+            expr = self.builder.new_binop(expr, BinOp::And, op_span, right, self.span_from(start));
+        }
+        Some(expr)
+    }
+
+    fn parse_equality(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let mut expr = self.parse_comparison()?;
+
+        while matches!(self.peek(), Token::EqualEqual | Token::NotEqual) {
+            let op_start = self.current_offset();
+            let op = match self.advance().0 {
+                Token::EqualEqual => BinOp::Eq,
+                Token::NotEqual => BinOp::Ne,
+                _ => unreachable!(),
+            };
+            let op_span = self.span_from(op_start);
+
+            let right = self.parse_comparison()?;
+            expr = self.builder.new_binop(expr, op, op_span, right, self.span_from(start));
+        }
+        Some(expr)
+    }
+
+    fn parse_comparison(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let mut expr = self.parse_additive()?;
+
+        while matches!(self.peek(), Token::Less | Token::Greater | Token::LessEqual | Token::GreaterEqual) {
+            let op_start = self.current_offset();
+            let op = match self.advance().0 {
+                Token::Less => BinOp::Lt,
+                Token::Greater => BinOp::Gt,
+                Token::LessEqual => BinOp::Le,
+                Token::GreaterEqual => BinOp::Ge,
+                _ => unreachable!(),
+            };
+            let op_span = self.span_from(op_start);
+
+            let right = self.parse_additive()?;
+            expr = self.builder.new_binop(expr, op, op_span, right, self.span_from(start));
+        }
+        Some(expr)
+    }
+
+    fn parse_additive(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let mut expr = self.parse_multiplicative()?;
 
         while matches!(self.peek(), Token::Plus | Token::Minus) {
+            let op_start = self.current_offset();
             let op = match self.advance().0 {
                 Token::Plus => BinOp::Add,
                 Token::Minus => BinOp::Sub,
                 _ => unreachable!(),
             };
+            let op_span = self.span_from(op_start);
 
-            let right = self.parse_primary()?;
-            expr = self.builder.new_binop(expr, op, right);
+            let right = self.parse_multiplicative()?;
+            expr = self.builder.new_binop(expr, op, op_span, right, self.span_from(start));
         }
         Some(expr)
     }
 
+    fn parse_multiplicative(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let mut expr = self.parse_unary()?;
+
+        while matches!(self.peek(), Token::Mul | Token::Div) {
+            let op_start = self.current_offset();
+            let op = match self.advance().0 {
+                Token::Mul => BinOp::Mul,
+                Token::Div => BinOp::Div,
+                _ => unreachable!(),
+            };
+            let op_span = self.span_from(op_start);
+
+            let right = self.parse_unary()?;
+            expr = self.builder.new_binop(expr, op, op_span, right, self.span_from(start));
+        }
+        Some(expr)
+    }
+
+    fn parse_unary(&mut self) -> Option<Expr> {
+        let start = self.current_offset();
+        let op = match self.peek() {
+            Token::LogicalNot => UnaryOp::Not,
+            Token::Minus => UnaryOp::Neg,
+            _ => return self.parse_primary(),
+        };
+        self.advance();
+        let operand = self.parse_unary()?;
+        Some(self.builder.new_unary(op, operand, self.span_from(start)))
+    }
+
     pub fn parse_primary(&mut self) -> Option<Expr> {
-        match self.advance() {
-
-            (Token::Identifier, text, _) => 
-                Some(self.builder.new_identifier(text)),
-                
-            (Token::Number, text, _) => {
-                let value: i32 = text.parse().unwrap_or_else(|_| {
-                    self.error("Invalid number literal");
-                    0
-                });
-                Some(self.builder.new_number(value))
+        if self.is_at_end() || !matches!(self.peek(), Token::Identifier | Token::Number | Token::LParen | Token::Error) {
+            self.error(ErrorCode::ExpectedExpression, "Expected expression");
+            return None;
+        }
+        let (token, text, offset) = self.advance();
+        let span = Span::new(offset, offset + text.len());
+        match token {
+            Token::Identifier =>
+                Some(self.builder.new_identifier(text, span)),
+
+            Token::LParen => {
+                let expr = self.parse_expr()?;
+                self.consume(Token::RParen, "Expected ')' after expression")?;
+                Some(expr)
             },
-            (tok, text, _) => {
-                self.error(&format!("Unexpected token '{:?}' in expression (found '{}')", tok, text));
-                None
-            }
+
+            Token::Number => {
+                // Literais fora do intervalo já foram reportados em `Parser::new`.
+                let value: i32 = text.parse().unwrap_or(0);
+                Some(self.builder.new_number(value, span))
+            },
+
+            // Token inválido já reportado em `Parser::new`: vira um nó de erro sem novo diagnóstico.
+            _ => Some(self.builder.new_error_expr(span)),
         }
     }
-}
\ No newline at end of file
+}
@@ -2,7 +2,7 @@ use crate::{
     parser::ast::Stmt,
     parser::ast_builder::ASTBuilder,
     error_handler::error_handler::ErrorHandler,
-    lexer::token::Token,
+    lexer::{lexer::lexical_errors, token::Token},
 };
 
 pub struct Parser<'a> {
@@ -11,17 +11,21 @@ pub struct Parser<'a> {
     pub pos: usize,
     pub errors: ErrorHandler,
     pub builder: ASTBuilder,
+    /// Offset do último erro reportado, para não repetir erros na mesma posição.
+    pub last_error: Option<usize>,
 }
 
 impl<'a> Parser<'a> {
+    /// Os erros léxicos dos tokens já entram nos erros do parser.
     pub fn new(tokens: Vec<(Token, &'a str, usize)>, source: &'a str) -> Self {
-
+        let errors = lexical_errors(&tokens, source);
         Parser {
             tokens,
             source,
             pos: 0,
-            errors: ErrorHandler::new(),
+            errors,
             builder: ASTBuilder::new(),
+            last_error: None,
         }
     }
 
@@ -32,14 +36,12 @@ impl<'a> Parser<'a> {
         while !self.is_at_end() {
             
             self.skip_newlines();
+            self.skip_invalid_tokens();
             if self.is_at_end() {
                 break;
             }
-            if let Some(stmt) = self.parse_stmt() {
-                statements.push(stmt);
-            } else {
-                self.advance();
-            }
+            let stmt = self.parse_stmt_or_recover();
+            statements.push(stmt);
         }
         (statements, std::mem::take(&mut self.errors))
     }
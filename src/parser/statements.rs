@@ -1,12 +1,28 @@
 use crate::{
-    parser::ast::Stmt,
+    parser::ast::{Expr, Span, Stmt},
+    error_handler::error_code::ErrorCode,
     lexer::token::Token,
+    symbol_table::symbol_type::SymbolType,
 };
 
 use super::{ast::{ActionCommand, Command, MoveCommand}, parser::Parser};
 
 impl<'a> Parser<'a> {
 
+    /// Se o statement falhar, sincroniza e devolve um `Stmt::Error` no lugar,
+    /// para que as fases seguintes ainda vejam o resto da AST.
+    pub fn parse_stmt_or_recover(&mut self) -> Stmt {
+        let start = self.current_offset();
+        let start_pos = self.pos;
+        match self.parse_stmt() {
+            Some(stmt) => stmt,
+            None => {
+                self.synchronize(start_pos);
+                self.builder.new_error_stmt(self.span_from(start))
+            }
+        }
+    }
+
     pub fn parse_stmt(&mut self) -> Option<Stmt> {
         match self.peek() {
             Token::MoveUp 
@@ -25,58 +41,149 @@ impl<'a> Parser<'a> {
             Token::For => 
                 self.parse_for(),
 
+            _ => self.parse_simple_stmt(),
+        }
+    }
+
+    /// Statements que cabem no cabeçalho de um `for`: declaração, atribuição ou expressão.
+    pub fn parse_simple_stmt(&mut self) -> Option<Stmt> {
+        match (self.peek(), self.peek_next()) {
+            (Token::Let, _) =>
+                self.parse_let(),
+            (Token::Identifier, Token::Assign) =>
+                self.parse_assign(),
+
+            (token, _) if !token.starts_expression() => {
+                self.error(ErrorCode::ExpectedStatement, "Expected statement");
+                None
+            }
             _ => {
-                if let Some(expr) = self.parse_expr() {
-                    Some(self.builder.new_expr_stmt(expr))
-                } else {
-                    self.error("Expected statement");
-                    self.advance();
-                    None
-                }
+                let expr = self.parse_expr()?;
+                Some(self.builder.new_expr_stmt(expr))
+            }
+        }
+    }
+
+    pub fn parse_let(&mut self) -> Option<Stmt> {
+        let start = self.current_offset();
+        self.consume(Token::Let, "Expected 'let'")?;
+        let name_start = self.current_offset();
+        let name = self.parse_identifier("Expected variable name after 'let'")?;
+        let name_span = self.span_from(name_start);
+
+        self.consume(Token::Colon, "Expected ':' after variable name")?;
+        let var_type = match self.peek() {
+            Token::IntType => SymbolType::Integer,
+            Token::BoolType => SymbolType::Boolean,
+            _ => {
+                self.error(ErrorCode::UnknownType, "Expected type 'int' or 'bool' after ':'");
+                return None;
             }
+        };
+        self.advance();
+
+        self.consume(Token::Assign, "Expected '=' in variable declaration")?;
+        let value = self.parse_expr()?;
+
+        Some(self.builder.new_let(&name, name_span, var_type, value, self.span_from(start)))
+    }
+
+    pub fn parse_assign(&mut self) -> Option<Stmt> {
+        let start = self.current_offset();
+        let name = self.parse_identifier("Expected variable name")?;
+        let name_span = self.span_from(start);
+
+        self.consume(Token::Assign, "Expected '=' in assignment")?;
+        let value = self.parse_expr()?;
+
+        Some(self.builder.new_assign(&name, name_span, value, self.span_from(start)))
+    }
+
+    fn parse_identifier(&mut self, msg: &str) -> Option<String> {
+        if self.check(Token::Identifier) {
+            Some(self.advance().1.to_string())
+        } else {
+            self.error(ErrorCode::ExpectedVariableName, msg);
+            None
         }
     }
 
     pub fn parse_if(&mut self) -> Option<Stmt> {
+        let start = self.current_offset();
         self.consume(Token::If, "Expected 'if'")?;
-        self.consume(Token::LParen, "Expected '(' after if")?;
-        let condition = self.parse_expr()?;
-
-        self.consume(Token::RParen, "Expected ')' after if condition")?;
+        let condition = self.parse_condition("if");
         let then_branch = self.parse_block();
 
-        self.consume(Token::Else, "Expected 'else' after if block")?;
-        let else_branch = self.parse_block();
+        // `else if` vira um IfStmt sozinho no else_branch; sem else, o ramo fica vazio.
+        let else_branch = if self.match_else() {
+            if self.check(Token::If) {
+                vec![self.parse_if()?]
+            } else {
+                self.parse_block()
+            }
+        } else {
+            Vec::new()
+        };
 
-        Some(self.builder.new_if(condition, then_branch, else_branch))
+        Some(self.builder.new_if(condition, then_branch, else_branch, self.span_from(start)))
+    }
+
+    /// Consome um `else`, que pode estar na linha seguinte ao `}`.
+    fn match_else(&mut self) -> bool {
+        let start = self.pos;
+        self.skip_newlines();
+        if self.check(Token::Else) {
+            self.advance();
+            true
+        } else {
+            self.pos = start;
+            false
+        }
     }
 
     pub fn parse_while(&mut self) -> Option<Stmt> {
+        let start = self.current_offset();
         self.consume(Token::While, "Expected 'while'")?;
-        self.consume(Token::LParen, "Expected '(' after while")?;
-        let condition = self.parse_expr()?;
-
-        self.consume(Token::RParen, "Expected ')' after while condition")?;
+        let condition = self.parse_condition("while");
         let body = self.parse_block();
 
-        Some(self.builder.new_while(condition, body))
+        Some(self.builder.new_while(condition, body, self.span_from(start)))
+    }
+
+    /// `( expr )` de `if` e `while`. Parênteses ausentes são reportados mas não
+    /// interrompem o statement; uma condição inválida vira `Expr::Error`.
+    fn parse_condition(&mut self, keyword: &str) -> Expr {
+        let _ = self.consume(Token::LParen, &format!("Expected '(' after {}", keyword));
+        let start = self.current_offset();
+        let condition = match self.parse_expr() {
+            Some(condition) => condition,
+            None => {
+                while !matches!(self.peek(), Token::RParen | Token::LBrace | Token::Newline) && !self.is_at_end() {
+                    self.advance();
+                }
+                self.builder.new_error_expr(self.span_from(start))
+            }
+        };
+        let _ = self.consume(Token::RParen, &format!("Expected ')' after {} condition", keyword));
+        condition
     }
 
     pub fn parse_for(&mut self) -> Option<Stmt> {
+        let start = self.current_offset();
         self.consume(Token::For, "Expected 'for'")?;
         self.consume(Token::LParen, "Expected '(' after for")?;
-        let init = self.parse_expr()?;
+        let init = self.parse_simple_stmt()?;
 
         self.consume(Token::Semicolon, "Expected ';' after initialization")?;
         let condition = self.parse_expr()?;
 
         self.consume(Token::Semicolon, "Expected ';' after condition")?;
-        let update = self.parse_expr()?;
+        let update = self.parse_simple_stmt()?;
 
         self.consume(Token::RParen, "Expected ')' after for clauses")?;
         let body = self.parse_block();
 
-        Some(self.builder.new_for(init, condition, update, body))
+        Some(self.builder.new_for(init, condition, update, body, self.span_from(start)))
     }
 
     pub fn parse_block(&mut self) -> Vec<Stmt> {
@@ -88,37 +195,36 @@ impl<'a> Parser<'a> {
 
         while !self.check(Token::RBrace) && !self.is_at_end() {
             self.skip_newlines();
-            if self.check(Token::RBrace) { break; }
-            if let Some(stmt) = self.parse_stmt() {
-                statements.push(stmt);
-            } else {
-                self.advance();
-            }
+            self.skip_invalid_tokens();
+            if self.check(Token::RBrace) || self.is_at_end() { break; }
+            let stmt = self.parse_stmt_or_recover();
+            statements.push(stmt);
         }
         self.consume(Token::RBrace, "Expected '}' to close block");
         statements
     }
 
     pub fn parse_command(&mut self) -> Option<Stmt> {
-        let token = self.advance().0;
-        
+        let (token, text, offset) = self.advance();
+        let span = Span::new(offset, offset + text.len());
+
         let stmt = match token {
             Token::MoveUp => 
-                self.builder.new_command(Command::Move(MoveCommand::MoveUp)),
+                self.builder.new_command(Command::Move(MoveCommand::MoveUp), span),
             Token::MoveDown => 
-                self.builder.new_command(Command::Move(MoveCommand::MoveDown)),
+                self.builder.new_command(Command::Move(MoveCommand::MoveDown), span),
             Token::MoveLeft => 
-                self.builder.new_command(Command::Move(MoveCommand::MoveLeft)),
+                self.builder.new_command(Command::Move(MoveCommand::MoveLeft), span),
             Token::MoveRight => 
-                self.builder.new_command(Command::Move(MoveCommand::MoveRight)),
+                self.builder.new_command(Command::Move(MoveCommand::MoveRight), span),
             Token::Jump => 
-                self.builder.new_command(Command::Action(ActionCommand::Jump)),
+                self.builder.new_command(Command::Action(ActionCommand::Jump), span),
             Token::Attack => 
-                self.builder.new_command(Command::Action(ActionCommand::Attack)),
+                self.builder.new_command(Command::Action(ActionCommand::Attack), span),
             Token::Defend => 
-                self.builder.new_command(Command::Action(ActionCommand::Defend)),
+                self.builder.new_command(Command::Action(ActionCommand::Defend), span),
             _ => {
-                self.error("Invalid command");
+                self.error(ErrorCode::ExpectedStatement, "Invalid command");
                 return None;
             }
         };
@@ -1,6 +1,7 @@
 use crate::{
-    error_handler::error_type::ErrorType,
+    error_handler::error_code::ErrorCode,
     lexer::token::Token,
+    parser::ast::Span,
 };
 
 use super::parser::Parser;
@@ -20,6 +21,13 @@ impl<'a> Parser<'a> {
         .unwrap_or_default()
     }
 
+    pub fn peek_next(&self) -> Token {
+        self.tokens
+        .get(self.pos + 1)
+        .map(|(t, _, _)| t.clone())
+        .unwrap_or_default()
+    }
+
     pub fn advance(&mut self) -> (Token, &'a str, usize) {
         let current =
          self.tokens
@@ -31,6 +39,25 @@ impl<'a> Parser<'a> {
         current
     }
 
+    /// Offset do token atual; no fim da entrada, o tamanho da fonte.
+    pub fn current_offset(&self) -> usize {
+        self.tokens
+        .get(self.pos)
+        .map(|(_, _, offset)| *offset)
+        .unwrap_or(self.source.len())
+    }
+
+    /// Span de `start` até o fim do último token consumido.
+    pub fn span_from(&self, start: usize) -> Span {
+        let last = self.pos.min(self.tokens.len());
+        let end = last
+            .checked_sub(1)
+            .and_then(|i| self.tokens.get(i))
+            .map(|(_, text, offset)| offset + text.len())
+            .unwrap_or(start);
+        Span::new(start, end.max(start))
+    }
+
     pub fn check(&self, tok: Token) -> bool {
         self.peek() == tok
     }
@@ -44,20 +71,64 @@ impl<'a> Parser<'a> {
             self.advance();
             Some(())
         } else {
-            self.error(msg);
+            self.error(expected_code(&expected), msg);
             None
         }
     }
 
-    pub fn error(&mut self, msg: &str) {
-        let (_token, text, offset) = self
-            .tokens
-            .get(self.pos)
-            .cloned()
-            .unwrap_or((Token::Error, "", 0));
-        let (line, col) = offset_to_line_col(self.source, offset);
-        self.errors
-            .add_error(ErrorType::Syntactic, &format!("{} (found '{}')", msg, text), line, col);
+    /// Modo pânico: descarta tokens até uma quebra de linha, um `}` ou o início
+    /// de outro statement. Sempre consome ao menos um token se o statement que
+    /// falhou começou em `start_pos` e não avançou, para não entrar em loop.
+    pub fn synchronize(&mut self, start_pos: usize) {
+        if self.pos == start_pos {
+            self.advance();
+        }
+        while !self.is_at_end() {
+            let token = self.peek();
+            if token == Token::Newline || token == Token::RBrace || token.starts_statement() {
+                return;
+            }
+            self.advance();
+        }
+    }
+
+    /// Pula tokens inválidos entre statements; já foram reportados em `Parser::new`.
+    pub fn skip_invalid_tokens(&mut self) {
+        while !self.is_at_end() && self.check(Token::Error) {
+            self.advance();
+        }
+    }
+
+    /// Reporta um erro no token atual, a menos que já exista um erro nessa posição.
+    pub fn error(&mut self, code: ErrorCode, msg: &str) {
+        let (text, span) = match self.tokens.get(self.pos) {
+            Some((_, text, offset)) => (*text, Span::new(*offset, offset + text.len())),
+            None => ("", Span::new(self.source.len(), self.source.len())),
+        };
+        if self.last_error == Some(span.start) {
+            return;
+        }
+        self.last_error = Some(span.start);
+        self.errors.add_error_at(
+            code,
+            &format!("{} (found '{}')", msg, text),
+            span,
+            self.source,
+        );
+    }
+}
+
+/// Código do erro quando `consume` não encontra o token esperado.
+fn expected_code(expected: &Token) -> ErrorCode {
+    match expected {
+        Token::LParen => ErrorCode::ExpectedOpenParen,
+        Token::RParen => ErrorCode::ExpectedCloseParen,
+        Token::LBrace => ErrorCode::ExpectedOpenBrace,
+        Token::RBrace => ErrorCode::ExpectedCloseBrace,
+        Token::Semicolon => ErrorCode::ExpectedSemicolon,
+        Token::Colon => ErrorCode::ExpectedTypeAnnotation,
+        Token::Assign => ErrorCode::ExpectedAssign,
+        _ => ErrorCode::ExpectedStatement,
     }
 }
 
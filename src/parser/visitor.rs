@@ -0,0 +1,209 @@
+use crate::parser::ast::{Command, Expr, Span, Stmt};
+
+/// Percorre a AST por referência.
+///
+/// Cada método tem uma implementação padrão que só desce para os filhos, então
+/// basta sobrescrever os nós que interessam. Quem sobrescreve `visit_stmt` ou
+/// `visit_expr` e ainda quer visitar os filhos chama `walk_stmt`/`walk_expr`.
+pub trait Visitor {
+    fn visit_stmts(&mut self, stmts: &[Stmt]) {
+        walk_stmts(self, stmts);
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr);
+    }
+
+    fn visit_command(&mut self, _command: &Command, _span: Span) {}
+}
+
+pub fn walk_stmts<V: Visitor + ?Sized>(visitor: &mut V, stmts: &[Stmt]) {
+    for stmt in stmts {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+/// Visita os filhos na ordem do código: no `for`, `init`, condição, corpo e só então `update`,
+/// que é a ordem em que são executados.
+pub fn walk_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Stmt) {
+    match stmt {
+        Stmt::Command(command, span) => visitor.visit_command(command, *span),
+        Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+            visitor.visit_expr(condition);
+            visitor.visit_stmts(then_branch);
+            visitor.visit_stmts(else_branch);
+        }
+        Stmt::WhileStmt { condition, body, .. } => {
+            visitor.visit_expr(condition);
+            visitor.visit_stmts(body);
+        }
+        Stmt::ForStmt { init, condition, update, body, .. } => {
+            visitor.visit_stmt(init);
+            visitor.visit_expr(condition);
+            visitor.visit_stmts(body);
+            visitor.visit_stmt(update);
+        }
+        Stmt::LetStmt { value, .. } | Stmt::AssignStmt { value, .. } => visitor.visit_expr(value),
+        Stmt::ExprStmt(expr) => visitor.visit_expr(expr),
+        Stmt::Error(_) => {}
+    }
+}
+
+pub fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Expr) {
+    match expr {
+        Expr::BinaryOp { left, right, .. } => {
+            visitor.visit_expr(left);
+            visitor.visit_expr(right);
+        }
+        Expr::UnaryOp { operand, .. } => visitor.visit_expr(operand),
+        Expr::Identifier(..) | Expr::Number(..) | Expr::Error(_) => {}
+    }
+}
+
+/// Como `Visitor`, mas pode alterar os nós no lugar.
+pub trait VisitorMut {
+    fn visit_stmts_mut(&mut self, stmts: &mut Vec<Stmt>) {
+        walk_stmts_mut(self, stmts);
+    }
+
+    fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+        walk_stmt_mut(self, stmt);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        walk_expr_mut(self, expr);
+    }
+
+    fn visit_command_mut(&mut self, _command: &mut Command, _span: &mut Span) {}
+}
+
+pub fn walk_stmts_mut<V: VisitorMut + ?Sized>(visitor: &mut V, stmts: &mut Vec<Stmt>) {
+    for stmt in stmts {
+        visitor.visit_stmt_mut(stmt);
+    }
+}
+
+pub fn walk_stmt_mut<V: VisitorMut + ?Sized>(visitor: &mut V, stmt: &mut Stmt) {
+    match stmt {
+        Stmt::Command(command, span) => visitor.visit_command_mut(command, span),
+        Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+            visitor.visit_expr_mut(condition);
+            visitor.visit_stmts_mut(then_branch);
+            visitor.visit_stmts_mut(else_branch);
+        }
+        Stmt::WhileStmt { condition, body, .. } => {
+            visitor.visit_expr_mut(condition);
+            visitor.visit_stmts_mut(body);
+        }
+        Stmt::ForStmt { init, condition, update, body, .. } => {
+            visitor.visit_stmt_mut(init);
+            visitor.visit_expr_mut(condition);
+            visitor.visit_stmts_mut(body);
+            visitor.visit_stmt_mut(update);
+        }
+        Stmt::LetStmt { value, .. } | Stmt::AssignStmt { value, .. } => visitor.visit_expr_mut(value),
+        Stmt::ExprStmt(expr) => visitor.visit_expr_mut(expr),
+        Stmt::Error(_) => {}
+    }
+}
+
+pub fn walk_expr_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut Expr) {
+    match expr {
+        Expr::BinaryOp { left, right, .. } => {
+            visitor.visit_expr_mut(left);
+            visitor.visit_expr_mut(right);
+        }
+        Expr::UnaryOp { operand, .. } => visitor.visit_expr_mut(operand),
+        Expr::Identifier(..) | Expr::Number(..) | Expr::Error(_) => {}
+    }
+}
+
+/// Reconstrói a AST consumindo os nós.
+///
+/// As implementações padrão (`fold_stmt`, `fold_expr`, ...) refazem cada nó com
+/// os filhos já transformados; `fold_span` é chamado para todo span, o que permite,
+/// por exemplo, apagar as posições antes de comparar duas árvores.
+pub trait Fold {
+    fn fold_stmts(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        fold_stmts(self, stmts)
+    }
+
+    fn fold_stmt(&mut self, stmt: Stmt) -> Stmt {
+        fold_stmt(self, stmt)
+    }
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        fold_expr(self, expr)
+    }
+
+    fn fold_span(&mut self, span: Span) -> Span {
+        span
+    }
+}
+
+pub fn fold_stmts<F: Fold + ?Sized>(folder: &mut F, stmts: Vec<Stmt>) -> Vec<Stmt> {
+    stmts.into_iter().map(|stmt| folder.fold_stmt(stmt)).collect()
+}
+
+pub fn fold_stmt<F: Fold + ?Sized>(folder: &mut F, stmt: Stmt) -> Stmt {
+    match stmt {
+        Stmt::Command(command, span) => Stmt::Command(command, folder.fold_span(span)),
+        Stmt::IfStmt { condition, then_branch, else_branch, span } => Stmt::IfStmt {
+            condition: folder.fold_expr(condition),
+            then_branch: folder.fold_stmts(then_branch),
+            else_branch: folder.fold_stmts(else_branch),
+            span: folder.fold_span(span),
+        },
+        Stmt::WhileStmt { condition, body, span } => Stmt::WhileStmt {
+            condition: folder.fold_expr(condition),
+            body: folder.fold_stmts(body),
+            span: folder.fold_span(span),
+        },
+        Stmt::ForStmt { init, condition, update, body, span } => {
+            let init = Box::new(folder.fold_stmt(*init));
+            let condition = folder.fold_expr(condition);
+            let body = folder.fold_stmts(body);
+            let update = Box::new(folder.fold_stmt(*update));
+            Stmt::ForStmt { init, condition, update, body, span: folder.fold_span(span) }
+        }
+        Stmt::LetStmt { name, name_span, var_type, value, span } => Stmt::LetStmt {
+            name,
+            name_span: folder.fold_span(name_span),
+            var_type,
+            value: folder.fold_expr(value),
+            span: folder.fold_span(span),
+        },
+        Stmt::AssignStmt { name, name_span, value, span } => Stmt::AssignStmt {
+            name,
+            name_span: folder.fold_span(name_span),
+            value: folder.fold_expr(value),
+            span: folder.fold_span(span),
+        },
+        Stmt::ExprStmt(expr) => Stmt::ExprStmt(folder.fold_expr(expr)),
+        Stmt::Error(span) => Stmt::Error(folder.fold_span(span)),
+    }
+}
+
+pub fn fold_expr<F: Fold + ?Sized>(folder: &mut F, expr: Expr) -> Expr {
+    match expr {
+        Expr::Identifier(name, span) => Expr::Identifier(name, folder.fold_span(span)),
+        Expr::Number(n, span) => Expr::Number(n, folder.fold_span(span)),
+        Expr::BinaryOp { left, op, op_span, right, span } => Expr::BinaryOp {
+            left: Box::new(folder.fold_expr(*left)),
+            op,
+            op_span: folder.fold_span(op_span),
+            right: Box::new(folder.fold_expr(*right)),
+            span: folder.fold_span(span),
+        },
+        Expr::UnaryOp { op, operand, span } => Expr::UnaryOp {
+            op,
+            operand: Box::new(folder.fold_expr(*operand)),
+            span: folder.fold_span(span),
+        },
+        Expr::Error(span) => Expr::Error(folder.fold_span(span)),
+    }
+}
@@ -1,18 +1,26 @@
-use crate::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr, BinOp};
+use crate::formatter::format_simple_stmt;
+use crate::parser::ast::{Stmt, Command, Expr, Span};
+use crate::parser::visitor::{walk_stmts, Visitor};
 
+/// Imprime a AST de forma legível, com dois espaços por nível.
+///
+/// É a implementação de referência de `Visitor`: os blocos passam por
+/// `visit_stmts`, que controla a indentação, e as expressões são escritas
+/// por `visit_expr` num buffer que vira parte da linha atual.
+#[derive(Default)]
 pub struct PrettyPrinter {
     lines: Vec<String>,
+    level: usize,
+    expr: String,
 }
 
 impl PrettyPrinter {
     pub fn new() -> Self {
-        PrettyPrinter { lines: Vec::new() }
+        PrettyPrinter::default()
     }
 
     pub fn print_stmts(&mut self, stmts: &[Stmt]) -> String {
-        for stmt in stmts {
-            self.print_stmt(stmt, 0);
-        }
+        walk_stmts(self, stmts);
         let mut output = self.lines.join("\n");
         if !output.is_empty() {
             output.push('\n');
@@ -20,66 +28,107 @@ impl PrettyPrinter {
         output
     }
 
-    fn print_stmt(&mut self, stmt: &Stmt, level: usize) {
-        let indent = if level > 0 { "  ".repeat(level) } else { String::new() };
+    fn line(&mut self, text: String) {
+        self.lines.push(format!("{}{}", "  ".repeat(self.level), text));
+    }
+
+    /// Imprime o then_branch e a cadeia de `else if`/`else`, omitindo else vazio.
+    fn print_if_rest(&mut self, then_branch: &[Stmt], else_branch: &[Stmt]) {
+        self.visit_stmts(then_branch);
+        match else_branch {
+            [] => self.line("}".to_string()),
+            [Stmt::IfStmt { condition, then_branch, else_branch, .. }] => {
+                let condition = self.format_expr(condition);
+                self.line(format!("}} else if ({}) {{", condition));
+                self.print_if_rest(then_branch, else_branch);
+            }
+            _ => {
+                self.line("} else {".to_string());
+                self.visit_stmts(else_branch);
+                self.line("}".to_string());
+            }
+        }
+    }
+
+    fn format_expr(&mut self, expr: &Expr) -> String {
+        self.visit_expr(expr);
+        std::mem::take(&mut self.expr)
+    }
+
+    fn visit_operand(&mut self, expr: &Expr, min_precedence: u8) {
+        if expr.precedence() < min_precedence {
+            self.expr.push('(');
+            self.visit_expr(expr);
+            self.expr.push(')');
+        } else {
+            self.visit_expr(expr);
+        }
+    }
+}
+
+impl Visitor for PrettyPrinter {
+    fn visit_stmts(&mut self, stmts: &[Stmt]) {
+        self.level += 1;
+        walk_stmts(self, stmts);
+        self.level -= 1;
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
         match stmt {
-            Stmt::Command(cmd) => {
-                self.lines.push(format!("{}Command: {}", indent, self.format_command(cmd)));
+            Stmt::Command(cmd, span) => self.visit_command(cmd, *span),
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                let condition = self.format_expr(condition);
+                self.line(format!("if ({}) {{", condition));
+                self.print_if_rest(then_branch, else_branch);
             }
-            Stmt::IfStmt { condition, then_branch, else_branch } => {
-                self.lines.push(format!("{}if ({}) {{", indent, self.format_expr(condition)));
-                for s in then_branch { self.print_stmt(s, level + 1); }
-                self.lines.push(format!("{}}} else {{", indent));
-                for s in else_branch { self.print_stmt(s, level + 1); }
-                self.lines.push(format!("{}}}", indent));
+            Stmt::WhileStmt { condition, body, .. } => {
+                let condition = self.format_expr(condition);
+                self.line(format!("while ({}) {{", condition));
+                self.visit_stmts(body);
+                self.line("}".to_string());
             }
-            Stmt::WhileStmt { condition, body } => {
-                self.lines.push(format!("{}while ({}) {{", indent, self.format_expr(condition)));
-                for s in body { self.print_stmt(s, level + 1); }
-                self.lines.push(format!("{}}}", indent));
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                let header = format!(
+                    "for ({}; {}; {}) {{",
+                    format_simple_stmt(init),
+                    self.format_expr(condition),
+                    format_simple_stmt(update)
+                );
+                self.line(header);
+                self.visit_stmts(body);
+                self.line("}".to_string());
             }
-            Stmt::ForStmt { init, condition, update, body } => {
-                self.lines.push(format!("{}for ({}; {}; {}) {{", indent, 
-                    self.format_expr(init), 
-                    self.format_expr(condition), 
-                    self.format_expr(update)
-                ));
-                for s in body { self.print_stmt(s, level + 1); }
-                self.lines.push(format!("{}}}", indent));
+            Stmt::LetStmt { .. } | Stmt::AssignStmt { .. } => {
+                self.line(format_simple_stmt(stmt));
             }
             Stmt::ExprStmt(expr) => {
-                self.lines.push(format!("{}Expr: {}", indent, self.format_expr(expr)));
+                let expr = self.format_expr(expr);
+                self.line(format!("Expr: {}", expr));
             }
+            Stmt::Error(_) => self.line("<error>".to_string()),
         }
     }
 
-    fn format_command(&self, cmd: &Command) -> String {
-        match cmd {
-            Command::Move(m) => match m {
-                MoveCommand::MoveUp => "move_up".into(),
-                MoveCommand::MoveDown => "move_down".into(),
-                MoveCommand::MoveLeft => "move_left".into(),
-                MoveCommand::MoveRight => "move_right".into(),
-            },
-            Command::Action(a) => match a {
-                ActionCommand::Jump => "jump".into(),
-                ActionCommand::Attack => "attack".into(),
-                ActionCommand::Defend => "defend".into(),
-            },
-        }
+    fn visit_command(&mut self, command: &Command, _span: Span) {
+        self.line(format!("Command: {}", command));
     }
 
-    fn format_expr(&self, expr: &Expr) -> String {
+    fn visit_expr(&mut self, expr: &Expr) {
         match expr {
-            Expr::Identifier(s) => s.clone(),
-            Expr::Number(n) => n.to_string(),
-            Expr::BinaryOp { left, op, right } => {
-                let op_str = match op {
-                    BinOp::Add => " + ",
-                    BinOp::Sub => " - ",
-                };
-                format!("({}{}{})", self.format_expr(left), op_str, self.format_expr(right))
+            Expr::Identifier(s, _) => self.expr.push_str(s),
+            Expr::Number(n, _) => self.expr.push_str(&n.to_string()),
+            Expr::Error(_) => self.expr.push_str("<error>"),
+            Expr::BinaryOp { left, op, right, .. } => {
+                // Operadores binários associam à esquerda: o lado direito com a
+                // mesma precedência precisa de parênteses, o esquerdo não.
+                self.visit_operand(left, op.precedence());
+                self.expr.push_str(&format!(" {} ", op));
+                self.visit_operand(right, op.precedence() + 1);
+            }
+            Expr::UnaryOp { op, operand, .. } => {
+                self.expr.push_str(&op.to_string());
+                self.visit_operand(operand, expr.precedence());
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,142 @@
+use crate::{
+    error_handler::{error_code::ErrorCode, error_handler::ErrorHandler},
+    parser::{ast::{Expr, Span, Stmt}, utils::offset_to_line_col},
+    symbol_table::{symbol_table::SymbolTable, symbol_type::SymbolType},
+    world::world::PREDEFINED_SYMBOLS,
+};
+
+/// Resolve cada identificador da AST contra os símbolos declarados ou pré-definidos.
+///
+/// Símbolos pré-definidos entram na tabela na primeira vez em que são usados,
+/// com a linha e a coluna desse uso.
+pub struct SemanticAnalyzer<'a> {
+    source: &'a str,
+    symbols: SymbolTable,
+    builtins: Vec<(&'static str, SymbolType)>,
+    errors: ErrorHandler,
+}
+
+impl<'a> SemanticAnalyzer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        SemanticAnalyzer {
+            source,
+            symbols: SymbolTable::new(),
+            builtins: PREDEFINED_SYMBOLS.to_vec(),
+            errors: ErrorHandler::new(),
+        }
+    }
+
+    pub fn analyze(mut self, stmts: &[Stmt]) -> (SymbolTable, ErrorHandler) {
+        self.visit_block(stmts);
+        (self.symbols, self.errors)
+    }
+
+    fn visit_block(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            self.visit_stmt(stmt);
+        }
+    }
+
+    /// Cada bloco `{ ... }` abre um escopo; o que for declarado nele some ao sair.
+    fn visit_scoped_block(&mut self, stmts: &[Stmt]) {
+        self.symbols.enter_scope();
+        self.visit_block(stmts);
+        self.symbols.exit_scope();
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Command(..) | Stmt::Error(_) => {}
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                self.visit_expr(condition);
+                self.visit_scoped_block(then_branch);
+                self.visit_scoped_block(else_branch);
+            }
+            Stmt::WhileStmt { condition, body, .. } => {
+                self.visit_expr(condition);
+                self.visit_scoped_block(body);
+            }
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                // O cabeçalho e o corpo compartilham o escopo da variável do laço.
+                self.symbols.enter_scope();
+                self.visit_stmt(init);
+                self.visit_expr(condition);
+                self.visit_stmt(update);
+                self.visit_block(body);
+                self.symbols.exit_scope();
+            }
+            Stmt::LetStmt { name, name_span, var_type, value, span } => {
+                self.visit_expr(value);
+                self.declare(name, *name_span, var_type, *span);
+            }
+            Stmt::AssignStmt { name, name_span, value, .. } => {
+                self.check_assignment(name, *name_span);
+                self.visit_expr(value);
+            }
+            Stmt::ExprStmt(expr) => self.visit_expr(expr),
+        }
+    }
+
+    /// Os erros apontam para o nome; a posição do símbolo é a do statement.
+    fn declare(&mut self, name: &str, name_span: Span, var_type: &SymbolType, span: Span) {
+        let (code, message) = if self.is_builtin(name) {
+            (ErrorCode::RedeclaredPredefined, format!("Cannot redeclare predefined symbol '{}'", name))
+        } else if let Some(previous) = self.symbols.lookup_current(name) {
+            (ErrorCode::AlreadyDeclared, format!("Variable '{}' was already declared on line {}", name, previous.line))
+        } else {
+            let (line, column) = self.position(span);
+            self.symbols.insert(name, var_type.clone(), line, column);
+            return;
+        };
+        self.errors.add_error_at(code, &message, name_span, self.source);
+    }
+
+    fn check_assignment(&mut self, name: &str, name_span: Span) {
+        let (code, message) = if self.is_builtin(name) {
+            (ErrorCode::AssignToPredefined, format!("Cannot assign to predefined symbol '{}'", name))
+        } else if self.symbols.lookup(name).is_none() {
+            (ErrorCode::AssignToUndeclared, format!("Assignment to undeclared variable '{}'", name))
+        } else {
+            return;
+        };
+        self.errors.add_error_at(code, &message, name_span, self.source);
+    }
+
+    fn position(&self, span: Span) -> (usize, usize) {
+        offset_to_line_col(self.source, span.start)
+    }
+
+    fn is_builtin(&self, name: &str) -> bool {
+        self.builtins.iter().any(|(builtin, _)| *builtin == name)
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Identifier(name, span) => self.resolve(name, *span),
+            Expr::Number(..) | Expr::Error(_) => {}
+            Expr::BinaryOp { left, right, .. } => {
+                self.visit_expr(left);
+                self.visit_expr(right);
+            }
+            Expr::UnaryOp { operand, .. } => self.visit_expr(operand),
+        }
+    }
+
+    fn resolve(&mut self, name: &str, span: Span) {
+        if self.symbols.lookup(name).is_some() {
+            return;
+        }
+        match self.builtins.iter().find(|(builtin, _)| *builtin == name) {
+            Some((_, symbol_type)) => {
+                let (line, column) = self.position(span);
+                self.symbols.insert_global(name, symbol_type.clone(), line, column);
+            }
+            None => self.errors.add_error_at(
+                ErrorCode::UndefinedIdentifier,
+                &format!("Undefined identifier '{}'", name),
+                span,
+                self.source,
+            ),
+        }
+    }
+}
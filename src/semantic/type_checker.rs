@@ -0,0 +1,199 @@
+use crate::{
+    error_handler::{error_code::ErrorCode, error_handler::ErrorHandler},
+    parser::{ast::{BinOp, Expr, Span, Stmt, UnaryOp}, utils::offset_to_line_col},
+    symbol_table::{symbol_table::SymbolTable, symbol_type::SymbolType},
+    world::world::PREDEFINED_SYMBOLS,
+};
+
+/// Regra para condições de `if`, `while` e `for`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConditionRule {
+    /// Inteiros também valem: diferente de zero é verdadeiro, como no interpretador.
+    Truthiness,
+    /// Apenas expressões booleanas.
+    BooleanOnly,
+}
+
+/// Infere o tipo de cada expressão e valida operadores e condições.
+///
+/// Identificadores desconhecidos ficam sem tipo e não geram erro aqui;
+/// quem os reporta é o `SemanticAnalyzer`.
+pub struct TypeChecker<'a> {
+    source: &'a str,
+    rule: ConditionRule,
+    symbols: SymbolTable,
+    errors: ErrorHandler,
+}
+
+impl<'a> TypeChecker<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let mut symbols = SymbolTable::new();
+        for (name, symbol_type) in PREDEFINED_SYMBOLS {
+            symbols.insert(name, symbol_type, 0, 0);
+        }
+        TypeChecker {
+            source,
+            rule: ConditionRule::Truthiness,
+            symbols,
+            errors: ErrorHandler::new(),
+        }
+    }
+
+    pub fn with_condition_rule(mut self, rule: ConditionRule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    pub fn check(mut self, stmts: &[Stmt]) -> ErrorHandler {
+        self.check_block(stmts);
+        self.errors
+    }
+
+    fn check_block(&mut self, stmts: &[Stmt]) {
+        for stmt in stmts {
+            self.check_stmt(stmt);
+        }
+    }
+
+    fn check_scoped_block(&mut self, stmts: &[Stmt]) {
+        self.symbols.enter_scope();
+        self.check_block(stmts);
+        self.symbols.exit_scope();
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Command(..) | Stmt::Error(_) => {}
+            Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
+                self.check_condition(condition, "if");
+                self.check_scoped_block(then_branch);
+                self.check_scoped_block(else_branch);
+            }
+            Stmt::WhileStmt { condition, body, .. } => {
+                self.check_condition(condition, "while");
+                self.check_scoped_block(body);
+            }
+            Stmt::ForStmt { init, condition, update, body, .. } => {
+                self.symbols.enter_scope();
+                self.check_stmt(init);
+                self.check_condition(condition, "for");
+                self.check_stmt(update);
+                self.check_block(body);
+                self.symbols.exit_scope();
+            }
+            Stmt::LetStmt { name, var_type, value, span, .. } => {
+                let found = self.infer(value);
+                self.check_value(name, var_type, found, value);
+                if self.symbols.lookup_current(name).is_none() {
+                    let (line, column) = self.position(span.start);
+                    self.symbols.insert(name, var_type.clone(), line, column);
+                }
+            }
+            Stmt::AssignStmt { name, value, .. } => {
+                let expected = self.symbols.lookup(name).map(|symbol| symbol.symbol_type.clone());
+                let found = self.infer(value);
+                if let Some(expected) = expected {
+                    self.check_value(name, &expected, found, value);
+                }
+            }
+            Stmt::ExprStmt(expr) => {
+                self.infer(expr);
+            }
+        }
+    }
+
+    /// Declarações e atribuições não convertem tipos, nem com a regra de truthiness.
+    fn check_value(&mut self, name: &str, expected: &SymbolType, found: Option<SymbolType>, value: &Expr) {
+        if let Some(found) = found.filter(|found| found != expected) {
+            self.errors.add_error_at(
+                ErrorCode::VariableTypeMismatch,
+                &format!("Variable '{}' has type {:?}, found {:?}", name, expected, found),
+                value.span(),
+                self.source,
+            );
+        }
+    }
+
+    fn check_condition(&mut self, condition: &Expr, keyword: &str) {
+        let found = self.infer(condition);
+        if let Some(found) = found.filter(|found| !self.accepts(&SymbolType::Boolean, found)) {
+            self.errors.add_error_at(
+                ErrorCode::ConditionNotBoolean,
+                &format!("Condition of '{}' must be Boolean, found {:?}", keyword, found),
+                condition.span(),
+                self.source,
+            );
+        }
+    }
+
+    /// Onde se espera Boolean, a regra de truthiness também aceita Integer.
+    fn accepts(&self, expected: &SymbolType, found: &SymbolType) -> bool {
+        found == expected || (*expected == SymbolType::Boolean && self.rule == ConditionRule::Truthiness)
+    }
+
+    fn position(&self, offset: usize) -> (usize, usize) {
+        offset_to_line_col(self.source, offset)
+    }
+
+    fn infer(&mut self, expr: &Expr) -> Option<SymbolType> {
+        match expr {
+            Expr::Identifier(name, _) => self.symbols.lookup(name).map(|symbol| symbol.symbol_type.clone()),
+            Expr::Number(..) => Some(SymbolType::Integer),
+            Expr::Error(_) => None,
+            Expr::BinaryOp { left, op, op_span, right, .. } => {
+                let left_type = self.infer(left);
+                let right_type = self.infer(right);
+                if matches!(op, BinOp::Eq | BinOp::Ne) {
+                    if let (Some(l), Some(r)) = (&left_type, &right_type) {
+                        if l != r {
+                            self.errors.add_error_at(
+                                ErrorCode::EqualityTypeMismatch,
+                                &format!("Operator '{}' expects operands of the same type, found {:?} and {:?}", op, l, r),
+                                *op_span,
+                                self.source,
+                            );
+                        }
+                    }
+                    return Some(SymbolType::Boolean);
+                }
+                let expected = match op {
+                    BinOp::And | BinOp::Or => SymbolType::Boolean,
+                    _ => SymbolType::Integer,
+                };
+                let mismatch = [left_type, right_type]
+                    .into_iter()
+                    .flatten()
+                    .find(|operand| !self.accepts(&expected, operand));
+                if let Some(found) = mismatch {
+                    self.errors.add_error_at(
+                        ErrorCode::OperandTypeMismatch,
+                        &format!("Operator '{}' expects {:?} operands, found {:?}", op, expected, found),
+                        *op_span,
+                        self.source,
+                    );
+                }
+                if op.is_comparison() {
+                    Some(SymbolType::Boolean)
+                } else {
+                    Some(expected)
+                }
+            }
+            Expr::UnaryOp { op, operand, span } => {
+                let operand_type = self.infer(operand);
+                let expected = match op {
+                    UnaryOp::Not => SymbolType::Boolean,
+                    UnaryOp::Neg => SymbolType::Integer,
+                };
+                if let Some(found) = operand_type.filter(|found| !self.accepts(&expected, found)) {
+                    self.errors.add_error_at(
+                        ErrorCode::UnaryOperandTypeMismatch,
+                        &format!("Operator '{}' expects a {:?} operand, found {:?}", op, expected, found),
+                        Span::new(span.start, span.start + op.to_string().len()),
+                        self.source,
+                    );
+                }
+                Some(expected)
+            }
+        }
+    }
+}
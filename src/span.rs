@@ -0,0 +1,33 @@
+use crate::parser::utils::offset_to_line_col;
+
+/// Intervalo de bytes `[start, end)` no código-fonte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+
+    /// Span que vai do início de `self` até o fim de `other`.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Converte para ((linha, coluna) inicial, (linha, coluna) final), ambas a partir de 1.
+    /// A posição final é a do primeiro caractere depois do span.
+    pub fn line_col_range(&self, source: &str) -> ((usize, usize), (usize, usize)) {
+        (offset_to_line_col(source, self.start), offset_to_line_col(source, self.end))
+    }
+}
@@ -2,9 +2,13 @@ use std::collections::HashMap;
 use crate::symbol_table::symbol_type::SymbolType;
 use crate::symbol_table::symbol::Symbol;
 
+#[derive(Default)]
 pub struct SymbolTable {
     pub symbols: HashMap<String, Symbol>,
     scope_level: usize,
+    /// Símbolos escondidos por uma declaração de mesmo nome num escopo interno,
+    /// com o nível desse escopo; voltam a valer quando ele termina.
+    shadowed: Vec<(usize, Symbol)>,
 }
 
 impl SymbolTable {
@@ -12,16 +16,21 @@ impl SymbolTable {
         SymbolTable {
             symbols: HashMap::new(),
             scope_level: 0,
+            shadowed: Vec::new(),
         }
     }
 
+    /// Um nome de um escopo de fora pode ser declarado de novo num escopo interno.
     pub fn insert(&mut self, name: &str, symbol_type: SymbolType, line: usize, column: usize) {
-        if self.symbols.contains_key(name) {
+        if self.lookup_current(name).is_some() {
             println!(
                 "Aviso: A variável '{}' já foi declarada no escopo atual. (Linha {}, Coluna {})",
                 name, line, column
             );
         } else {
+            if let Some(outer) = self.symbols.remove(name) {
+                self.shadowed.push((self.scope_level, outer));
+            }
             self.symbols.insert(
                 name.to_string(),
                 Symbol {
@@ -35,22 +44,40 @@ impl SymbolTable {
         }
     }
 
+    /// Insere no escopo global, mesmo de dentro de um bloco (ex.: símbolos pré-definidos).
+    pub fn insert_global(&mut self, name: &str, symbol_type: SymbolType, line: usize, column: usize) {
+        let scope_level = self.scope_level;
+        self.scope_level = 0;
+        self.insert(name, symbol_type, line, column);
+        self.scope_level = scope_level;
+    }
+
     pub fn lookup(&self, name: &str) -> Option<&Symbol> {
         self.symbols.get(name)
     }
 
+    /// Como `lookup`, mas só encontra símbolos declarados no escopo atual.
+    pub fn lookup_current(&self, name: &str) -> Option<&Symbol> {
+        self.lookup(name).filter(|symbol| symbol.scope_level == self.scope_level)
+    }
+
     pub fn enter_scope(&mut self) {
         self.scope_level += 1;
     }
 
     pub fn exit_scope(&mut self) {
         self.symbols.retain(|_, sym| sym.scope_level < self.scope_level);
+        while self.shadowed.last().is_some_and(|(level, _)| *level == self.scope_level) {
+            if let Some((_, outer)) = self.shadowed.pop() {
+                self.symbols.insert(outer.name.clone(), outer);
+            }
+        }
         self.scope_level -= 1;
     }
 
     pub fn print_table(&self) {
         println!("Tabela de Símbolos:");
-        for (_, symbol) in &self.symbols {
+        for symbol in self.symbols.values() {
             println!(
                 "  - {} : {:?} (Escopo: {}, Linha: {}, Coluna: {})",
                 symbol.name, symbol.symbol_type, symbol.scope_level, symbol.line, symbol.column
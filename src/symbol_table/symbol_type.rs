@@ -3,3 +3,21 @@ pub enum SymbolType {
     Integer,
     Boolean,
 }
+
+impl SymbolType {
+    /// Palavra-chave usada nas anotações de tipo (`let x: int = ...`).
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            SymbolType::Integer => "int",
+            SymbolType::Boolean => "bool",
+        }
+    }
+
+    pub fn from_keyword(keyword: &str) -> Option<SymbolType> {
+        match keyword {
+            "int" => Some(SymbolType::Integer),
+            "bool" => Some(SymbolType::Boolean),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod world;
+pub mod position;
+pub mod map_parser;
@@ -0,0 +1,131 @@
+use crate::{
+    error_handler::{error_code::ErrorCode, error_handler::ErrorHandler},
+    world::{position::Position, world::World},
+};
+
+pub const SEPARATOR: &str = "---";
+
+/// Lê um mapa em texto: um cabeçalho `chave: valor`, a linha `---` e o grid ASCII.
+///
+/// ```text
+/// name: Primeira Missão
+/// health: 10
+/// ---
+/// #######
+/// #H.E.T#
+/// #..^..#
+/// #######
+/// ```
+///
+/// Legenda: `#` parede, `.` chão, `H` herói, `E` inimigo, `T` tesouro, `^` armadilha.
+pub struct MapParser<'a> {
+    source: &'a str,
+    errors: ErrorHandler,
+}
+
+impl<'a> MapParser<'a> {
+    pub fn new(source: &'a str) -> Self {
+        MapParser {
+            source,
+            errors: ErrorHandler::new(),
+        }
+    }
+
+    pub fn parse(mut self) -> (Option<World>, ErrorHandler) {
+        let mut name = String::new();
+        let mut health = None;
+        let mut lines = self.source.lines().enumerate();
+        let mut separator_line = None;
+
+        for (index, raw) in lines.by_ref() {
+            let line = raw.trim();
+            if line == SEPARATOR {
+                separator_line = Some(index + 1);
+                break;
+            }
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            match line.split_once(':') {
+                Some((key, value)) => match key.trim() {
+                    "name" => name = value.trim().to_string(),
+                    "health" => match value.trim().parse::<i32>() {
+                        Ok(value) if value > 0 => health = Some(value),
+                        _ => self.error(
+                            ErrorCode::InvalidHealth,
+                            &format!("Invalid health '{}': expected a positive integer", value.trim()),
+                            index + 1,
+                            1,
+                        ),
+                    },
+                    other => self.error(ErrorCode::UnknownHeaderKey, &format!("Unknown map header key '{}'", other), index + 1, 1),
+                },
+                None => self.error(ErrorCode::MalformedHeaderLine, &format!("Expected 'key: value' in map header (found '{}')", line), index + 1, 1),
+            }
+        }
+
+        let Some(separator_line) = separator_line else {
+            let last_line = self.source.lines().count().max(1);
+            self.error(ErrorCode::MissingSeparator, &format!("Missing '{}' between map header and grid", SEPARATOR), last_line, 1);
+            return (None, self.errors);
+        };
+
+        let mut rows: Vec<(usize, &str)> = lines.map(|(index, row)| (index + 1, row.trim_end())).collect();
+        while rows.last().is_some_and(|(_, row)| row.is_empty()) {
+            rows.pop();
+        }
+        if rows.is_empty() {
+            self.error(ErrorCode::EmptyGrid, "Map grid is empty", separator_line, 1);
+            return (None, self.errors);
+        }
+
+        let width = rows[0].1.chars().count();
+        let mut world = World::new(width as i32, rows.len() as i32);
+        world.name = name;
+        if let Some(health) = health {
+            world.hero.health = health;
+        }
+
+        let mut hero_found = false;
+        for (y, (line, row)) in rows.iter().enumerate() {
+            let cells = row.chars().count();
+            if cells != width {
+                self.error(ErrorCode::RaggedRow, &format!("Map row has {} cells, expected {}", cells, width), *line, 1);
+            }
+            for (x, cell) in row.chars().enumerate() {
+                let position = Position::new(x as i32, y as i32);
+                // Numa linha irregular, o que passa da largura só é validado.
+                let inside = x < width;
+                match cell {
+                    '.' => {}
+                    '#' | 'E' | 'T' | '^' if !inside => {}
+                    '#' => world.add_wall(position),
+                    'E' => world.add_enemy(position),
+                    'T' => world.add_treasure(position),
+                    '^' => world.add_trap(position),
+                    'H' if hero_found => self.error(ErrorCode::DuplicateHero, "Map has more than one hero ('H')", *line, x + 1),
+                    'H' => {
+                        hero_found = true;
+                        if inside {
+                            world.place_hero(position);
+                        }
+                    }
+                    other => self.error(ErrorCode::UnknownCell, &format!("Unknown map cell '{}'", other), *line, x + 1),
+                }
+            }
+        }
+        if !hero_found {
+            self.error(ErrorCode::MissingHero, "Map has no hero ('H')", separator_line, 1);
+        }
+
+        if self.errors.has_errors() {
+            (None, self.errors)
+        } else {
+            (Some(world), self.errors)
+        }
+    }
+
+    fn error(&mut self, code: ErrorCode, msg: &str, line: usize, column: usize) {
+        self.errors.add_coded_error(code, msg, line, column);
+    }
+}
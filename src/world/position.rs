@@ -0,0 +1,51 @@
+use crate::parser::ast::MoveCommand;
+
+/// Coordenada no grid. `y` cresce para baixo, como as linhas de um mapa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    pub fn new(x: i32, y: i32) -> Self {
+        Position { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Position {
+        match direction {
+            Direction::Up => Position::new(self.x, self.y - 1),
+            Direction::Down => Position::new(self.x, self.y + 1),
+            Direction::Left => Position::new(self.x - 1, self.y),
+            Direction::Right => Position::new(self.x + 1, self.y),
+        }
+    }
+
+    pub fn neighbours(self) -> [Position; 4] {
+        [
+            self.step(Direction::Up),
+            self.step(Direction::Down),
+            self.step(Direction::Left),
+            self.step(Direction::Right),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl From<&MoveCommand> for Direction {
+    fn from(command: &MoveCommand) -> Self {
+        match command {
+            MoveCommand::MoveUp => Direction::Up,
+            MoveCommand::MoveDown => Direction::Down,
+            MoveCommand::MoveLeft => Direction::Left,
+            MoveCommand::MoveRight => Direction::Right,
+        }
+    }
+}
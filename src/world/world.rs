@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+
+use crate::interpreter::value::Value;
+use crate::parser::ast::{ActionCommand, Command};
+use crate::symbol_table::symbol_type::SymbolType;
+use crate::world::position::{Direction, Position};
+
+pub const DEFAULT_WIDTH: i32 = 10;
+pub const DEFAULT_HEIGHT: i32 = 10;
+pub const DEFAULT_HEALTH: i32 = 10;
+pub const ENEMY_DAMAGE: i32 = 1;
+pub const TRAP_DAMAGE: i32 = 2;
+
+/// Nomes que todo programa enxerga sem declarar, com os tipos dos fatos do mundo:
+/// vida do herói, inimigo à frente, tesouros restantes e armadilha à frente.
+/// O valor de cada um vem de `World::predefined`.
+pub const PREDEFINED_SYMBOLS: [(&str, SymbolType); 4] = [
+    ("hero", SymbolType::Integer),
+    ("enemy", SymbolType::Boolean),
+    ("treasure", SymbolType::Integer),
+    ("trap", SymbolType::Boolean),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hero {
+    pub position: Position,
+    pub health: i32,
+    pub facing: Direction,
+    pub defending: bool,
+    pub treasures: i32,
+}
+
+/// Resultado de um comando executado pelo herói.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Moved,
+    Blocked,
+    TreasureCollected,
+    TrapTriggered,
+    EnemyDefeated(Position),
+    AttackMissed,
+    Defended,
+}
+
+/// Grid 2D onde o herói executa o programa.
+#[derive(Debug, Clone, PartialEq)]
+pub struct World {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub hero: Hero,
+    pub walls: HashSet<Position>,
+    pub enemies: HashSet<Position>,
+    pub treasures: HashSet<Position>,
+    pub traps: HashSet<Position>,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        World::new(DEFAULT_WIDTH, DEFAULT_HEIGHT)
+    }
+}
+
+impl World {
+    pub fn new(width: i32, height: i32) -> Self {
+        World {
+            name: String::new(),
+            width,
+            height,
+            hero: Hero {
+                position: Position::new(0, 0),
+                health: DEFAULT_HEALTH,
+                facing: Direction::Right,
+                defending: false,
+                treasures: 0,
+            },
+            walls: HashSet::new(),
+            enemies: HashSet::new(),
+            treasures: HashSet::new(),
+            traps: HashSet::new(),
+        }
+    }
+
+    pub fn place_hero(&mut self, position: Position) {
+        self.hero.position = position;
+    }
+
+    pub fn add_wall(&mut self, position: Position) {
+        self.walls.insert(position);
+    }
+
+    pub fn add_enemy(&mut self, position: Position) {
+        self.enemies.insert(position);
+    }
+
+    pub fn add_treasure(&mut self, position: Position) {
+        self.treasures.insert(position);
+    }
+
+    pub fn add_trap(&mut self, position: Position) {
+        self.traps.insert(position);
+    }
+
+    pub fn in_bounds(&self, position: Position) -> bool {
+        position.x >= 0 && position.y >= 0 && position.x < self.width && position.y < self.height
+    }
+
+    /// Uma célula é livre quando está dentro do grid e não tem parede nem inimigo.
+    pub fn is_free(&self, position: Position) -> bool {
+        self.in_bounds(position)
+            && !self.walls.contains(&position)
+            && !self.enemies.contains(&position)
+    }
+
+    pub fn ahead(&self) -> Position {
+        self.hero.position.step(self.hero.facing)
+    }
+
+    pub fn enemy_ahead(&self) -> bool {
+        self.enemies.contains(&self.ahead())
+    }
+
+    pub fn trap_ahead(&self) -> bool {
+        self.traps.contains(&self.ahead())
+    }
+
+    pub fn treasures_left(&self) -> i32 {
+        self.treasures.len() as i32
+    }
+
+    /// Valor atual de um símbolo de `PREDEFINED_SYMBOLS`; `None` para outros nomes.
+    pub fn predefined(&self, name: &str) -> Option<Value> {
+        match name {
+            "hero" => Some(Value::Int(self.hero.health)),
+            "enemy" => Some(Value::Bool(self.enemy_ahead())),
+            "treasure" => Some(Value::Int(self.treasures_left())),
+            "trap" => Some(Value::Bool(self.trap_ahead())),
+            _ => None,
+        }
+    }
+
+    pub fn hero_defeated(&self) -> bool {
+        self.hero.health <= 0
+    }
+
+    /// Executa um comando do herói e, em seguida, o turno dos inimigos.
+    pub fn perform(&mut self, command: &Command) -> Outcome {
+        self.hero.defending = false;
+        let outcome = match command {
+            Command::Move(movement) => {
+                self.hero.facing = Direction::from(movement);
+                let target = self.ahead();
+                self.move_hero(target)
+            }
+            Command::Action(ActionCommand::Jump) => {
+                let over = self.ahead();
+                let target = over.step(self.hero.facing);
+                if !self.in_bounds(over) || self.walls.contains(&over) {
+                    Outcome::Blocked
+                } else {
+                    self.move_hero(target)
+                }
+            }
+            Command::Action(ActionCommand::Attack) => {
+                let target = self.ahead();
+                if self.enemies.remove(&target) {
+                    Outcome::EnemyDefeated(target)
+                } else {
+                    Outcome::AttackMissed
+                }
+            }
+            Command::Action(ActionCommand::Defend) => {
+                self.hero.defending = true;
+                Outcome::Defended
+            }
+        };
+        self.enemies_turn();
+        outcome
+    }
+
+    fn move_hero(&mut self, target: Position) -> Outcome {
+        if !self.is_free(target) {
+            return Outcome::Blocked;
+        }
+        self.hero.position = target;
+        if self.treasures.remove(&target) {
+            self.hero.treasures += 1;
+            Outcome::TreasureCollected
+        } else if self.traps.contains(&target) {
+            self.hero.health -= TRAP_DAMAGE;
+            Outcome::TrapTriggered
+        } else {
+            Outcome::Moved
+        }
+    }
+
+    fn enemies_turn(&mut self) {
+        if self.hero.defending {
+            return;
+        }
+        let attackers = self
+            .hero
+            .position
+            .neighbours()
+            .iter()
+            .filter(|p| self.enemies.contains(p))
+            .count() as i32;
+        self.hero.health -= attackers * ENEMY_DAMAGE;
+    }
+}
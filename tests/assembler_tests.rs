@@ -0,0 +1,141 @@
+use questlang::bytecode::assembler::Assembler;
+use questlang::bytecode::compiler::Compiler;
+use questlang::bytecode::disassembler::Disassembler;
+use questlang::bytecode::instruction::{Chunk, Instruction};
+use questlang::bytecode::vm::Vm;
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::{BinOp, Stmt};
+use questlang::parser::parser::Parser;
+use questlang::world::position::Position;
+use questlang::world::world::World;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    Parser::new(Lexer::new(source).tokenize(), source).parse().0
+}
+
+fn assemble(text: &str) -> Chunk {
+    let (chunk, errors) = Assembler::new(text).assemble();
+    assert!(!errors.has_errors(), "Assembly deve ser válido: {:?}", errors.errors());
+    chunk.expect("Sem erros deve haver um chunk")
+}
+
+fn first_error_code(text: &str) -> String {
+    let (chunk, errors) = Assembler::new(text).assemble();
+    assert!(chunk.is_none(), "Assembly inválido não gera chunk:\n{}", text);
+    errors.errors()[0].code.map(|code| code.to_string()).unwrap_or_default()
+}
+
+#[test]
+fn test_disassembly_matches_golden_file() {
+    let source = std::fs::read_to_string("tests/golden/while_loop.quest").unwrap();
+    let expected = std::fs::read_to_string("tests/golden/while_loop.qasm").unwrap();
+    let chunk = Compiler::new().compile(&parse(&source));
+    assert_eq!(Disassembler::new(&chunk).with_source(&source).disassemble(), expected);
+    assert_eq!(assemble(&expected).code, chunk.code, "O arquivo golden deve ser lido de volta");
+}
+
+#[test]
+fn test_disassembly_round_trips() {
+    let paths = [
+        "codigo-questlang/main.quest",
+        "codigo-questlang/aninhado.quest",
+        "codigo-questlang/erros.quest",
+        "codigo-questlang/big-file.quest",
+    ];
+    for path in paths {
+        let source = std::fs::read_to_string(path).unwrap();
+        let chunk = Compiler::new().compile(&parse(&source));
+        for text in [Disassembler::new(&chunk).disassemble(), Disassembler::new(&chunk).with_source(&source).disassemble()] {
+            let assembled = assemble(&text);
+            assert_eq!(assembled.code, chunk.code, "Instruções diferentes depois de remontar {}", path);
+            assert_eq!(assembled.slots, chunk.slots);
+        }
+    }
+}
+
+#[test]
+fn test_handwritten_program_runs() {
+    let text = "\
+.slot i
+push_int 0
+init 0 int
+load 0         ; while (i < 3)
+push_int 3
+lt
+jump_if_false 13
+tick
+command move_right
+load 0
+push_int 1
+add
+store 0
+jump 2
+";
+    let (trace, errors) = Vm::new(World::new(5, 1)).run(&assemble(text));
+    assert!(!errors.has_errors());
+    assert_eq!(trace.world.hero.position, Position::new(3, 0));
+}
+
+#[test]
+fn test_runtime_errors_point_at_assembly_line() {
+    let text = "push_int 1\npush_int 0\ndiv\npop";
+    let (_, errors) = Vm::new(World::default()).with_source(text).run(&assemble(text));
+    let error = &errors.errors()[0];
+    assert_eq!(error.message, "Division by zero");
+    assert_eq!((error.line, error.column), (3, 1));
+}
+
+#[test]
+fn test_vm_rejects_unverified_chunks() {
+    // Chunks montados à mão, sem passar pelo Assembler: a VM reporta em vez de entrar em pânico.
+    let underflow = Chunk { code: vec![Instruction::PushInt(1), Instruction::Binary(BinOp::Add)], spans: Vec::new(), slots: Vec::new() };
+    let bad_slot = Chunk { code: vec![Instruction::Load(3)], spans: Vec::new(), slots: vec!["x".to_string()] };
+    for (chunk, expected) in [(underflow, "Q0505"), (bad_slot, "Q0504")] {
+        let (trace, errors) = Vm::new(World::default()).run(&chunk);
+        let codes: Vec<_> = errors.errors().iter().filter_map(|error| error.code.map(|code| code.code())).collect();
+        assert_eq!(codes, [expected], "O chunk {:?} deve ser rejeitado antes de executar", chunk.code);
+        assert!(trace.events.is_empty());
+    }
+}
+
+#[test]
+fn test_disassemble_chunk_without_spans() {
+    // Sem spans, as instruções ficam com a posição padrão, na primeira linha.
+    let chunk = Chunk { code: vec![Instruction::PushInt(1), Instruction::Pop], spans: Vec::new(), slots: Vec::new() };
+    let text = Disassembler::new(&chunk).with_source("x").disassemble();
+    assert_eq!(text, "; 1 | x\n0000  push_int 1\n0001  pop\n");
+}
+
+#[test]
+fn test_operands_and_indices() {
+    let chunk = assemble("0000 push_bool true\n0001  fail Q0201 \"say \\\"hi\\\" ; not a comment\"\n\n; comentário\ncommand defend\n");
+    assert_eq!(chunk.code[0], Instruction::PushBool(true));
+    assert_eq!(chunk.code[1].to_string(), "fail Q0201 \"say \\\"hi\\\" ; not a comment\"");
+    assert_eq!(chunk.len(), 3);
+}
+
+#[test]
+fn test_assembly_errors_carry_codes() {
+    let cases = [
+        ("push 1", "Q0501"),
+        ("push_int", "Q0502"),
+        ("push_int x", "Q0502"),
+        ("command fly", "Q0502"),
+        ("init 0 float", "Q0502"),
+        ("fail Q0201 \"aberto", "Q0502"),
+        ("5 tick", "Q0502"),
+        ("jump 9", "Q0503"),
+        ("push_int 1\nstore 0", "Q0504"),
+        ("add", "Q0505"),
+        ("push_bool true\njump_if_false 3\npush_int 1\npop", "Q0505"),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(first_error_code(text), expected, "Código incorreto para:\n{}", text);
+    }
+}
+
+#[test]
+fn test_code_after_fail_is_not_checked() {
+    let chunk = assemble("fail Q0305 \"Undefined identifier 'x'\"\npush_int 1\nadd\npop");
+    assert_eq!(chunk.len(), 4, "Instruções inalcançáveis não afetam a pilha");
+}
@@ -1,12 +1,15 @@
-use questlang::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr, BinOp};
+use questlang::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr, BinOp, UnaryOp, Span};
 use questlang::parser::ast_builder::ASTBuilder;
+use questlang::symbol_table::symbol_type::SymbolType;
+
+const SPAN: Span = Span { start: 0, end: 0 };
 
 #[test]
 fn test_new_command() {
     let builder = ASTBuilder::new();
-    let stmt = builder.new_command(Command::Move(MoveCommand::MoveUp));
+    let stmt = builder.new_command(Command::Move(MoveCommand::MoveUp), SPAN);
     match stmt {
-        Stmt::Command(cmd) => match cmd {
+        Stmt::Command(cmd, _) => match cmd {
             Command::Move(mv) => {
                 assert_eq!(mv, MoveCommand::MoveUp, "Deve ser move_up");
             },
@@ -19,15 +22,15 @@ fn test_new_command() {
 #[test]
 fn test_new_if() {
     let builder = ASTBuilder::new();
-    let condition = builder.new_identifier("hero");
-    let then_branch = vec![builder.new_command(Command::Move(MoveCommand::MoveLeft))];
-    let else_branch = vec![builder.new_command(Command::Move(MoveCommand::MoveRight))];
-    let stmt = builder.new_if(condition, then_branch.clone(), else_branch.clone());
+    let condition = builder.new_identifier("hero", SPAN);
+    let then_branch = vec![builder.new_command(Command::Move(MoveCommand::MoveLeft), SPAN)];
+    let else_branch = vec![builder.new_command(Command::Move(MoveCommand::MoveRight), SPAN)];
+    let stmt = builder.new_if(condition, then_branch.clone(), else_branch.clone(), SPAN);
     
     match stmt {
-        Stmt::IfStmt { condition: cond, then_branch: tb, else_branch: eb } => {
+        Stmt::IfStmt { condition: cond, then_branch: tb, else_branch: eb, .. } => {
             match cond {
-                Expr::Identifier(s) => assert_eq!(s, "hero", "Condição deve ser 'hero'"),
+                Expr::Identifier(s, _) => assert_eq!(s, "hero", "Condição deve ser 'hero'"),
                 _ => panic!("Condição do if deve ser um identificador"),
             }
             assert_eq!(tb, then_branch, "Then branch incorreto");
@@ -40,14 +43,14 @@ fn test_new_if() {
 #[test]
 fn test_new_while() {
     let builder = ASTBuilder::new();
-    let condition = builder.new_identifier("enemy");
-    let body = vec![builder.new_command(Command::Action(ActionCommand::Jump))];
-    let stmt = builder.new_while(condition, body.clone());
+    let condition = builder.new_identifier("enemy", SPAN);
+    let body = vec![builder.new_command(Command::Action(ActionCommand::Jump), SPAN)];
+    let stmt = builder.new_while(condition, body.clone(), SPAN);
     
     match stmt {
-        Stmt::WhileStmt { condition: cond, body: b } => {
+        Stmt::WhileStmt { condition: cond, body: b, .. } => {
             match cond {
-                Expr::Identifier(s) => assert_eq!(s, "enemy", "Condição deve ser 'enemy'"),
+                Expr::Identifier(s, _) => assert_eq!(s, "enemy", "Condição deve ser 'enemy'"),
                 _ => panic!("Condição do while deve ser um identificador"),
             }
             assert_eq!(b, body, "Corpo do while incorreto");
@@ -59,24 +62,24 @@ fn test_new_while() {
 #[test]
 fn test_new_for() {
     let builder = ASTBuilder::new();
-    let init = builder.new_identifier("hero");
-    let condition = builder.new_identifier("enemy");
-    let update = builder.new_identifier("treasure");
-    let body = vec![builder.new_command(Command::Action(ActionCommand::Defend))];
-    let stmt = builder.new_for(init, condition, update, body.clone());
+    let init = builder.new_expr_stmt(builder.new_identifier("hero", SPAN));
+    let condition = builder.new_identifier("enemy", SPAN);
+    let update = builder.new_expr_stmt(builder.new_identifier("treasure", SPAN));
+    let body = vec![builder.new_command(Command::Action(ActionCommand::Defend), SPAN)];
+    let stmt = builder.new_for(init, condition, update, body.clone(), SPAN);
     
     match stmt {
-        Stmt::ForStmt { init: i, condition: cond, update: u, body: b } => {
-            match i {
-                Expr::Identifier(s) => assert_eq!(s, "hero", "Init deve ser 'hero'"),
+        Stmt::ForStmt { init: i, condition: cond, update: u, body: b, .. } => {
+            match *i {
+                Stmt::ExprStmt(Expr::Identifier(s, _)) => assert_eq!(s, "hero", "Init deve ser 'hero'"),
                 _ => panic!("Esperado identificador para init do for"),
             }
             match cond {
-                Expr::Identifier(s) => assert_eq!(s, "enemy", "Condition deve ser 'enemy'"),
+                Expr::Identifier(s, _) => assert_eq!(s, "enemy", "Condition deve ser 'enemy'"),
                 _ => panic!("Esperado identificador para condition do for"),
             }
-            match u {
-                Expr::Identifier(s) => assert_eq!(s, "treasure", "Update deve ser 'treasure'"),
+            match *u {
+                Stmt::ExprStmt(Expr::Identifier(s, _)) => assert_eq!(s, "treasure", "Update deve ser 'treasure'"),
                 _ => panic!("Esperado identificador para update do for"),
             }
             assert_eq!(b, body, "Corpo do for incorreto");
@@ -88,16 +91,16 @@ fn test_new_for() {
 #[test]
 fn test_new_expr_stmt() {
     let builder = ASTBuilder::new();
-    let left = builder.new_number(10);
-    let right = builder.new_number(3);
-    let bin_expr = builder.new_binop(left, BinOp::Sub, right);
+    let left = builder.new_number(10, SPAN);
+    let right = builder.new_number(3, SPAN);
+    let bin_expr = builder.new_binop(left, BinOp::Sub, SPAN, right, SPAN);
     let stmt = builder.new_expr_stmt(bin_expr);
     
     match stmt {
         Stmt::ExprStmt(expr) => match expr {
-            Expr::BinaryOp { left: l, op, right: r } => {
+            Expr::BinaryOp { left: l, op, right: r, .. } => {
                 match *l {
-                    Expr::Number(n) => assert_eq!(n, 10, "Esquerda deve ser 10"),
+                    Expr::Number(n, _) => assert_eq!(n, 10, "Esquerda deve ser 10"),
                     _ => panic!("Esperado número na esquerda"),
                 }
                 match op {
@@ -105,7 +108,7 @@ fn test_new_expr_stmt() {
                     _ => panic!("Esperado operador de subtração"),
                 }
                 match *r {
-                    Expr::Number(n) => assert_eq!(n, 3, "Direita deve ser 3"),
+                    Expr::Number(n, _) => assert_eq!(n, 3, "Direita deve ser 3"),
                     _ => panic!("Esperado número na direita"),
                 }
             },
@@ -118,9 +121,9 @@ fn test_new_expr_stmt() {
 #[test]
 fn test_new_identifier() {
     let builder = ASTBuilder::new();
-    let expr = builder.new_identifier("hero");
+    let expr = builder.new_identifier("hero", SPAN);
     match expr {
-        Expr::Identifier(s) => assert_eq!(s, "hero", "Deve criar identificador 'hero'"),
+        Expr::Identifier(s, _) => assert_eq!(s, "hero", "Deve criar identificador 'hero'"),
         _ => panic!("Esperado nó de identificador"),
     }
 }
@@ -128,9 +131,9 @@ fn test_new_identifier() {
 #[test]
 fn test_new_number() {
     let builder = ASTBuilder::new();
-    let expr = builder.new_number(42);
+    let expr = builder.new_number(42, SPAN);
     match expr {
-        Expr::Number(n) => assert_eq!(n, 42, "Deve criar número 42"),
+        Expr::Number(n, _) => assert_eq!(n, 42, "Deve criar número 42"),
         _ => panic!("Esperado nó de número"),
     }
 }
@@ -138,13 +141,13 @@ fn test_new_number() {
 #[test]
 fn test_new_binop() {
     let builder = ASTBuilder::new();
-    let left = builder.new_number(5);
-    let right = builder.new_number(3);
-    let expr = builder.new_binop(left, BinOp::Add, right);
+    let left = builder.new_number(5, SPAN);
+    let right = builder.new_number(3, SPAN);
+    let expr = builder.new_binop(left, BinOp::Add, SPAN, right, SPAN);
     match expr {
-        Expr::BinaryOp { left: l, op, right: r } => {
+        Expr::BinaryOp { left: l, op, right: r, .. } => {
             match *l {
-                Expr::Number(n) => assert_eq!(n, 5, "Esquerda deve ser 5"),
+                Expr::Number(n, _) => assert_eq!(n, 5, "Esquerda deve ser 5"),
                 _ => panic!("Esperado número na esquerda"),
             }
             match op {
@@ -152,10 +155,65 @@ fn test_new_binop() {
                 _ => panic!("Esperado operador de adição"),
             }
             match *r {
-                Expr::Number(n) => assert_eq!(n, 3, "Direita deve ser 3"),
+                Expr::Number(n, _) => assert_eq!(n, 3, "Direita deve ser 3"),
                 _ => panic!("Esperado número na direita"),
             }
         },
         _ => panic!("Esperado nó de expressão binária"),
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_new_unary() {
+    let builder = ASTBuilder::new();
+    let expr = builder.new_unary(UnaryOp::Not, builder.new_identifier("enemy", SPAN), SPAN);
+    match expr {
+        Expr::UnaryOp { op, operand, .. } => {
+            assert_eq!(op, UnaryOp::Not, "Esperado operador de negação lógica");
+            assert_eq!(*operand, Expr::Identifier("enemy".to_string(), SPAN), "Operando deve ser 'enemy'");
+        },
+        _ => panic!("Esperado nó de expressão unária"),
+    }
+}
+
+#[test]
+fn test_new_let_and_assign() {
+    let builder = ASTBuilder::new();
+    let stmt = builder.new_let("steps", SPAN, SymbolType::Integer, builder.new_number(3, SPAN), SPAN);
+    match stmt {
+        Stmt::LetStmt { name, var_type, value, .. } => {
+            assert_eq!(name, "steps", "Nome deve ser 'steps'");
+            assert_eq!(var_type, SymbolType::Integer, "Tipo deve ser Integer");
+            assert_eq!(value, Expr::Number(3, SPAN), "Valor deve ser 3");
+        },
+        _ => panic!("Esperado statement do tipo LetStmt"),
+    }
+
+    let stmt = builder.new_assign("steps", SPAN, builder.new_number(0, SPAN), SPAN);
+    match stmt {
+        Stmt::AssignStmt { name, value, .. } => {
+            assert_eq!(name, "steps", "Nome deve ser 'steps'");
+            assert_eq!(value, Expr::Number(0, SPAN), "Valor deve ser 0");
+        },
+        _ => panic!("Esperado statement do tipo AssignStmt"),
+    }
+}
+
+#[test]
+fn test_nodes_keep_their_spans() {
+    let builder = ASTBuilder::new();
+    let left = builder.new_identifier("hero", Span::new(0, 4));
+    let right = builder.new_number(1, Span::new(7, 8));
+    let expr = builder.new_binop(left, BinOp::Add, Span::new(5, 6), right, Span::new(0, 8));
+    assert_eq!(expr.span(), Span::new(0, 8), "O span do nó binário deve cobrir os dois operandos");
+    assert!(
+        matches!(expr, Expr::BinaryOp { op_span, .. } if op_span == Span::new(5, 6)),
+        "O nó binário guarda o span do operador"
+    );
+
+    let stmt = builder.new_expr_stmt(expr);
+    assert_eq!(stmt.span(), Span::new(0, 8), "ExprStmt usa o span da própria expressão");
+
+    let command = builder.new_command(Command::Action(ActionCommand::Jump), Span::new(9, 13));
+    assert_eq!(command.span(), Span::new(9, 13));
+}
@@ -0,0 +1,128 @@
+use questlang::json::Json;
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::{BinOp, Command, Expr, MoveCommand, Span, Stmt, UnaryOp};
+use questlang::parser::ast_json::{from_json, from_json_str, to_json};
+use questlang::parser::parser::Parser;
+use questlang::symbol_table::symbol_type::SymbolType;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    Parser::new(Lexer::new(source).tokenize(), source).parse().0
+}
+
+fn round_trip(ast: &[Stmt]) -> Vec<Stmt> {
+    let text = to_json(ast).to_string();
+    from_json_str(&text).unwrap_or_else(|msg| panic!("Falha ao ler o JSON gerado: {}\n{}", msg, text))
+}
+
+#[test]
+fn test_samples_round_trip() {
+    // Inclui os exemplos com erros: os nós de erro também precisam voltar iguais.
+    let paths = [
+        "codigo-questlang/main.quest",
+        "codigo-questlang/aninhado.quest",
+        "codigo-questlang/big-file.quest",
+        "codigo-questlang/erros.quest",
+        "codigo-questlang/big-erros.quest",
+    ];
+    for path in paths {
+        let source = std::fs::read_to_string(path).expect("Arquivo de exemplo deve existir");
+        let ast = parse(&source);
+        assert_eq!(round_trip(&ast), ast, "A AST de {} deve sobreviver à ida e volta", path);
+    }
+}
+
+#[test]
+fn test_encoding_is_stable() {
+    let ast = parse("let x: int = -(1 + 2)\nx = x * 3\nif (!trap) { move_up }\n");
+    let expected = concat!(
+        r#"{"version":1,"statements":["#,
+        r#"{"kind":"let","name":"x","name_span":{"start":4,"end":5},"type":"int","value":{"kind":"unary","op":"-","operand":{"kind":"binary","op":"+","#,
+        r#""op_span":{"start":17,"end":18},"#,
+        r#""left":{"kind":"number","value":1,"span":{"start":15,"end":16}},"#,
+        r#""right":{"kind":"number","value":2,"span":{"start":19,"end":20}},"span":{"start":15,"end":20}},"#,
+        r#""span":{"start":13,"end":21}},"span":{"start":0,"end":21}},"#,
+        r#"{"kind":"assign","name":"x","name_span":{"start":22,"end":23},"value":{"kind":"binary","op":"*","op_span":{"start":28,"end":29},"#,
+        r#""left":{"kind":"identifier","name":"x","span":{"start":26,"end":27}},"#,
+        r#""right":{"kind":"number","value":3,"span":{"start":30,"end":31}},"span":{"start":26,"end":31}},"span":{"start":22,"end":31}},"#,
+        r#"{"kind":"if","condition":{"kind":"unary","op":"!","operand":{"kind":"identifier","name":"trap","span":{"start":37,"end":41}},"#,
+        r#""span":{"start":36,"end":41}},"then":[{"kind":"command","command":"move_up","span":{"start":45,"end":52}}],"else":[],"#,
+        r#""span":{"start":32,"end":54}}]}"#,
+    );
+    assert_eq!(to_json(&ast).to_string(), expected, "O formato do JSON não deve mudar");
+}
+
+#[test]
+fn test_nodes_without_position_omit_the_span() {
+    let ast = vec![
+        Stmt::LetStmt {
+            name: "ok".to_string(),
+            name_span: Span::default(),
+            var_type: SymbolType::Boolean,
+            value: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier("enemy".to_string(), Span::default())),
+                op: BinOp::Or,
+                op_span: Span::default(),
+                right: Box::new(Expr::UnaryOp {
+                    op: UnaryOp::Not,
+                    operand: Box::new(Expr::Identifier("trap".to_string(), Span::default())),
+                    span: Span::default(),
+                }),
+                span: Span::default(),
+            },
+            span: Span::default(),
+        },
+        Stmt::Command(Command::Move(MoveCommand::MoveDown), Span::default()),
+    ];
+    let text = to_json(&ast).to_string();
+    assert!(!text.contains("span"), "Nós sem posição não devem ter span: {}", text);
+    assert_eq!(round_trip(&ast), ast);
+}
+
+#[test]
+fn test_for_and_error_nodes() {
+    let ast = parse("for (let i: int = 0; i < 3; i = i + 1) { jump }\nwhile ( { attack }\nlet y: int = 2 +\n");
+    assert!(ast.iter().any(|stmt| matches!(stmt, Stmt::WhileStmt { condition: Expr::Error(_), .. })));
+    assert_eq!(round_trip(&ast), ast);
+}
+
+#[test]
+fn test_decode_errors_point_to_the_node() {
+    let cases = [
+        (r#"{"statements":[]}"#, "$: missing field 'version'"),
+        (r#"{"version":2,"statements":[]}"#, "$.version: unsupported version 2"),
+        (r#"{"version":1,"statements":{}}"#, "$.statements: expected an array"),
+        (r#"{"version":1,"statements":[{"kind":"goto"}]}"#, "$.statements[0]: unknown statement kind 'goto'"),
+        (
+            r#"{"version":1,"statements":[{"kind":"command","command":"fly"}]}"#,
+            "$.statements[0].command: unknown command 'fly'",
+        ),
+        (
+            r#"{"version":1,"statements":[{"kind":"while","condition":{"kind":"identifier","name":"enemy"},"body":[{"kind":"expr","expr":{"kind":"binary","op":"%"}}]}]}"#,
+            "$.statements[0].body[0].expr.op: unknown binary operator '%'",
+        ),
+        (
+            r#"{"version":1,"statements":[{"kind":"expr","expr":{"kind":"number","value":3000000000}}]}"#,
+            "$.statements[0].expr.value: expected a 32-bit integer, found 3000000000",
+        ),
+        (
+            r#"{"version":1,"statements":[{"kind":"error","span":{"start":5,"end":2}}]}"#,
+            "$.statements[0].span: start 5 is after end 2",
+        ),
+        (r#"{"version":1,"statements":[{"kind":"let","name":"x","type":"str"}]}"#, "$.statements[0].type: unknown type 'str'"),
+        (r#"{"version":1,"statements":[3]}"#, "$.statements[0]: expected an object, found 3"),
+        (r#"{"version":1,"statements":["#, "unexpected end of input"),
+    ];
+    for (text, expected) in cases {
+        let msg = from_json_str(text).expect_err(text);
+        assert!(msg.starts_with(expected), "Para {} esperava '{}', obtido '{}'", text, expected, msg);
+    }
+}
+
+#[test]
+fn test_unknown_fields_are_ignored() {
+    let json = Json::parse(r#"{"version":1,"editor":{"zoom":2},"statements":[{"kind":"command","command":"jump","color":"red"}]}"#).unwrap();
+    assert_eq!(
+        from_json(&json),
+        Ok(vec![Stmt::Command(Command::from_keyword("jump").unwrap(), Span::default())])
+    );
+}
@@ -0,0 +1,136 @@
+use questlang::bytecode::compiler::Compiler;
+use questlang::bytecode::instruction::{Builtin, Instruction};
+use questlang::bytecode::vm::Vm;
+use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::interpreter::interpreter::Interpreter;
+use questlang::interpreter::trace::Trace;
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::Stmt;
+use questlang::parser::parser::Parser;
+use questlang::world::map_parser::MapParser;
+use questlang::world::position::Position;
+use questlang::world::world::World;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    let (ast, errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido");
+    ast
+}
+
+fn messages(errors: &ErrorHandler) -> Vec<String> {
+    errors.errors().iter().map(|e| e.to_string()).collect()
+}
+
+/// Executa na VM e no interpretador e confere que o resultado observável é o mesmo.
+fn run_both(source: &str, world: World, max_steps: usize) -> (Trace, ErrorHandler) {
+    let ast = parse(source);
+    let (expected_trace, expected_errors) = Interpreter::new(world.clone()).with_source(source).with_max_steps(max_steps).run(&ast);
+    let chunk = Compiler::new().compile(&ast);
+    let (trace, errors) = Vm::new(world).with_source(source).with_max_steps(max_steps).run(&chunk);
+    assert_eq!(trace, expected_trace, "A VM deve produzir o mesmo trace que o interpretador para:\n{}", source);
+    assert_eq!(messages(&errors), messages(&expected_errors), "A VM deve produzir os mesmos erros para:\n{}", source);
+    (trace, errors)
+}
+
+fn arena() -> World {
+    let mut world = World::new(6, 3);
+    world.add_enemy(Position::new(2, 0));
+    world.add_treasure(Position::new(4, 1));
+    world.add_trap(Position::new(1, 2));
+    world.add_wall(Position::new(3, 2));
+    world
+}
+
+#[test]
+fn test_vm_matches_interpreter() {
+    let programs = [
+        "move_right\nattack\nmove_right\nmove_down\nmove_right\nmove_right",
+        "while (!enemy) { move_right }\nattack\nif (enemy) { defend } else { jump }",
+        "for (let i: int = 0; i < 4; i = i + 1) { move_right\n if (i == 2) { move_down } }",
+        "let steps: int = 3\nwhile (steps > 0) { move_right\nsteps = steps - 1 }\nif (treasure > 0 && hero >= 1) { move_down } else if (trap || enemy) { jump } else { defend }",
+        "let x: int = (2 + 3) * 4 - 10 / 2\nif (x == 15 && !(x < 0)) { move_down }",
+        "let a: bool = enemy || trap\nlet b: bool = a && hero > 100\nif (b == false) { move_down } else { move_up }",
+        "if (hero) { let x: int = 1\n move_right }\nlet x: bool = enemy\nif (x) { attack }",
+        "let n: int = 0\nwhile (n < 3) { let n: int = 10\n move_down }\nmove_right",
+        "2 + 3\nenemy\nmove_left",
+    ];
+    for source in programs {
+        run_both(source, arena(), 1000);
+    }
+}
+
+#[test]
+fn test_vm_reports_the_same_runtime_errors() {
+    let programs = [
+        "move_right\nlet x: int = 1 / (hero - hero)\nmove_down",
+        "let x: int = enemy",
+        "let x: int = 1\nx = trap",
+        "undefined_thing = 1",
+        "move_right\nif (ghost) { jump }",
+        "let big: int = 2147483647\nbig = big + 1",
+        "let y: int = -(0 - 2147483647 - 1)",
+        "if (enemy < 1) { jump }",
+        "if (hero == enemy) { jump }",
+        "let z: int = -enemy",
+    ];
+    for source in programs {
+        let (_, errors) = run_both(source, arena(), 1000);
+        assert!(errors.has_errors(), "'{}' deveria falhar", source);
+    }
+}
+
+#[test]
+fn test_vm_respects_step_limit() {
+    let (trace, errors) = run_both("while (1 == 1) { defend }", World::default(), 25);
+    assert!(errors.has_errors());
+    assert!(trace.events.len() < 25);
+    run_both("for (let i: int = 0; 1 == 1; i = i + 1) { }", World::default(), 10);
+}
+
+#[test]
+fn test_vm_stops_when_hero_is_defeated() {
+    let mut world = World::new(3, 1);
+    world.add_enemy(Position::new(1, 0));
+    world.hero.health = 1;
+    let (trace, errors) = run_both("while (1 == 1) { move_left }", world, 1000);
+    assert!(!errors.has_errors(), "Derrota não é erro de execução");
+    assert!(trace.world.hero_defeated());
+}
+
+#[test]
+fn test_vm_runs_sample_map() {
+    let map = std::fs::read_to_string("codigo-questlang/mapa.qmap").expect("Mapa de exemplo deve existir");
+    let (world, errors) = MapParser::new(&map).parse();
+    assert!(!errors.has_errors());
+    let source = "while (!enemy) { move_right }\nattack\nfor (let i: int = 0; i < 5; i = i + 1) { move_right }\nmove_down\nwhile (treasure > 1) { move_down\n move_left }";
+    run_both(source, world.expect("Mapa válido"), 10_000);
+}
+
+#[test]
+fn test_jumps_are_patched() {
+    let chunk = Compiler::new().compile(&parse("while (enemy) { attack }\njump"));
+    assert_eq!(
+        chunk.code,
+        vec![
+            Instruction::LoadBuiltin(Builtin::from_name("enemy").unwrap()),
+            Instruction::JumpIfFalse(5),
+            Instruction::Tick,
+            Instruction::Command(questlang::parser::ast::Command::Action(questlang::parser::ast::ActionCommand::Attack)),
+            Instruction::Jump(0),
+            Instruction::Command(questlang::parser::ast::Command::Action(questlang::parser::ast::ActionCommand::Jump)),
+        ]
+    );
+    assert_eq!(chunk.spans.len(), chunk.code.len(), "Toda instrução tem um span de origem");
+
+    let chunk = Compiler::new().compile(&parse("if (hero) { jump } else { defend }"));
+    assert_eq!(chunk.code[1], Instruction::JumpIfFalse(4), "O salto do if cai no começo do else");
+    assert_eq!(chunk.code[3], Instruction::Jump(5), "O fim do then pula o else");
+}
+
+#[test]
+fn test_each_let_gets_its_own_slot() {
+    let chunk = Compiler::new().compile(&parse("let x: int = 1\nif (hero) { let x: int = 2\n x = 3 }\nx = 4"));
+    assert_eq!(chunk.slots, vec!["x".to_string(), "x".to_string()]);
+    let stores: Vec<&Instruction> = chunk.code.iter().filter(|i| matches!(i, Instruction::Store(_))).collect();
+    assert_eq!(stores, vec![&Instruction::Store(1), &Instruction::Store(0)], "Cada atribuição usa o slot visível no escopo");
+}
@@ -0,0 +1,150 @@
+use questlang::cfg::cfg::{BasicBlock, Cfg, Terminator};
+use questlang::cfg::dominators::Dominators;
+use questlang::cfg::reachability::{constant_condition, Reachability};
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::Stmt;
+use questlang::parser::parser::Parser;
+
+fn build(source: &str) -> Cfg {
+    let (ast, errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido:\n{}", source);
+    Cfg::build(&ast)
+}
+
+/// Sucessores de cada bloco, para comparar a forma do grafo.
+fn edges(cfg: &Cfg) -> Vec<Vec<usize>> {
+    (0..cfg.blocks.len()).map(|block| cfg.successors(block)).collect()
+}
+
+#[test]
+fn test_straight_line_is_one_block() {
+    let cfg = build("move_up\nlet x: int = 1\nx = x + 1\njump");
+    assert_eq!(cfg.blocks.len(), 1, "Sem desvios, o programa é um bloco só");
+    assert_eq!((cfg.entry, cfg.exit), (0, 0));
+    assert_eq!(cfg.blocks[0].stmts.len(), 4);
+    assert_eq!(cfg.blocks[0].terminator, Terminator::Exit);
+}
+
+#[test]
+fn test_if_else_joins() {
+    let cfg = build("move_up\nif (hero > 1) { jump } else { attack }\ndefend");
+    assert_eq!(edges(&cfg), [vec![1, 2], vec![3], vec![3], vec![]]);
+    assert_eq!(cfg.exit, 3);
+    assert!(matches!(cfg.blocks[3].stmts[..], [Stmt::Command(..)]), "O statement seguinte vai para o bloco de junção");
+}
+
+#[test]
+fn test_if_without_else_falls_through() {
+    let cfg = build("if (enemy) { attack }");
+    assert_eq!(edges(&cfg), [vec![1, 2], vec![2], vec![]]);
+}
+
+#[test]
+fn test_while_loops_back_to_header() {
+    let cfg = build("while (enemy) { attack }\njump");
+    assert_eq!(edges(&cfg), [vec![1], vec![2, 3], vec![1], vec![]]);
+    assert!(cfg.blocks[1].stmts.is_empty(), "O cabeçalho só avalia a condição");
+    assert_eq!(cfg.predecessors()[1], [0, 2], "O cabeçalho é alcançado pela entrada e pelo fim do corpo");
+}
+
+#[test]
+fn test_for_places_init_and_update() {
+    let cfg = build("for (let i: int = 0; i < 3; i = i + 1) { jump }");
+    assert_eq!(edges(&cfg), [vec![1], vec![2, 3], vec![1], vec![]]);
+    assert!(matches!(cfg.blocks[0].stmts[..], [Stmt::LetStmt { .. }]), "O init fica antes do laço");
+    assert!(
+        matches!(cfg.blocks[2].stmts[..], [Stmt::Command(..), Stmt::AssignStmt { .. }]),
+        "O update fica no fim do corpo"
+    );
+}
+
+#[test]
+fn test_nested_control_flow() {
+    let cfg = build("while (enemy) {\n  if (trap) { jump } else { while (hero > 2) { defend } }\n}\nmove_up");
+    // 0 entrada, 1 cabeçalho externo, 2 corpo/if, 3 then, 4 else, 5 cabeçalho interno,
+    // 6 corpo interno, 7 depois do laço interno, 8 junção do if, 9 saída.
+    assert_eq!(
+        edges(&cfg),
+        [vec![1], vec![2, 9], vec![3, 4], vec![8], vec![5], vec![6, 7], vec![5], vec![8], vec![1], vec![]]
+    );
+    assert_eq!(cfg.exit, 9);
+}
+
+#[test]
+fn test_dominators_of_nested_loops() {
+    // Mesmo programa de test_nested_control_flow.
+    let cfg = build("while (enemy) {\n  if (trap) { jump } else { while (hero > 2) { defend } }\n}\nmove_up");
+    let dominators = Dominators::compute(&cfg);
+    let idoms: Vec<Option<usize>> = (0..cfg.blocks.len()).map(|block| dominators.immediate_dominator(block)).collect();
+    assert_eq!(
+        idoms,
+        [None, Some(0), Some(1), Some(2), Some(2), Some(4), Some(5), Some(5), Some(2), Some(1)],
+        "A junção do if é dominada pelo desvio, não por um dos ramos"
+    );
+    assert!(dominators.dominates(1, 6), "O cabeçalho externo domina o laço interno");
+    assert!(!dominators.dominates(3, 8), "Um ramo do if não domina a junção");
+    assert!(dominators.dominates(8, 8), "Todo bloco domina a si mesmo");
+    assert_eq!(dominators.dominators_of(6), [6, 5, 4, 2, 1, 0]);
+    assert_eq!(dominators.back_edges(&cfg), [(6, 5), (8, 1)], "Cada laço tem uma aresta de volta ao cabeçalho");
+}
+
+#[test]
+fn test_blocks_outside_the_graph_have_no_dominator() {
+    // Bloco 2 não é alvo de nenhuma aresta.
+    let block = |terminator| BasicBlock { stmts: Vec::new(), terminator };
+    let cfg = Cfg {
+        blocks: vec![block(Terminator::Goto(1)), block(Terminator::Exit), block(Terminator::Goto(1))],
+        entry: 0,
+        exit: 1,
+    };
+    let dominators = Dominators::compute(&cfg);
+    assert_eq!(dominators.immediate_dominator(1), Some(0), "O bloco solto não muda o dominador da saída");
+    assert_eq!(dominators.immediate_dominator(2), None);
+    assert!(!dominators.dominates(2, 2));
+    assert!(!Reachability::compute(&cfg).is_reachable(2));
+}
+
+#[test]
+fn test_constant_conditions_cut_edges() {
+    let cfg = build("while (0) { attack }\nif (1) { jump } else { defend }\nif (hero) { move_up }");
+    let reachability = Reachability::compute(&cfg);
+    // 0 entrada, 1 cabeçalho, 2 corpo do while, 3 depois/if(1), 4 then, 5 else, 6 junção/if(hero), 7 then, 8 saída.
+    assert_eq!(edges(&cfg)[3], [4, 5], "O grafo mantém as duas arestas");
+    let reachable: Vec<bool> = (0..cfg.blocks.len()).map(|block| reachability.is_reachable(block)).collect();
+    assert_eq!(reachable, [true, true, false, true, true, false, true, true, true]);
+    assert_eq!(reachability.unreachable_code(&cfg), [2, 5], "O corpo de while (0) e o else de if (1) nunca rodam");
+}
+
+#[test]
+fn test_constant_conditions_with_logic_and_comparisons() {
+    let cases = [
+        ("3 > 2", Some(true)),
+        ("!1", Some(false)),
+        ("1 && 0", Some(false)),
+        ("0 || 2 - 2 == 0", Some(true)),
+        ("0 && hero", Some(false)),
+        ("1 || enemy", Some(true)),
+        ("hero && 0", None),
+        ("1 / 0 > 0", None),
+    ];
+    for (condition, expected) in cases {
+        let source = format!("if ({}) {{ jump }}", condition);
+        let cfg = build(&source);
+        let Terminator::Branch { condition: expr, .. } = &cfg.blocks[cfg.entry].terminator else {
+            panic!("Esperava um desvio na entrada");
+        };
+        assert_eq!(constant_condition(expr), expected, "Valor incorreto para '{}'", condition);
+    }
+}
+
+#[test]
+fn test_everything_is_reachable_without_constants() {
+    let source = std::fs::read_to_string("codigo-questlang/aninhado.quest").expect("Arquivo de exemplo deve existir");
+    let cfg = build(&source);
+    let reachability = Reachability::compute(&cfg);
+    let dominators = Dominators::compute(&cfg);
+    for block in 0..cfg.blocks.len() {
+        assert!(reachability.is_reachable(block), "Bloco {} deveria ser alcançável", block);
+        assert!(dominators.dominates(cfg.entry, block), "A entrada domina todos os blocos");
+    }
+}
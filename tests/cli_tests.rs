@@ -0,0 +1,127 @@
+use questlang::cli::{parse_args, Command, Emit, ErrorFormat, Invocation, Options, Verbosity};
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(String::from).collect()
+}
+
+fn compile(line: &str) -> Options {
+    match parse_args(&args(line)) {
+        Ok(Invocation::Compile(options)) => options,
+        other => panic!("Esperado Compile para '{}', obtido {:?}", line, other),
+    }
+}
+
+#[test]
+fn test_subcommands() {
+    let cases = [
+        ("lex prog.quest", Command::Lex),
+        ("parse prog.quest", Command::Parse),
+        ("check prog.quest", Command::Check),
+        ("fmt prog.quest", Command::Fmt),
+        ("disasm prog.quest", Command::Disasm),
+        ("run prog.quest", Command::Run),
+    ];
+    for (line, expected) in cases {
+        let options = compile(line);
+        assert_eq!(options.command, expected, "Comando incorreto para '{}'", line);
+        assert_eq!(options.source, "prog.quest");
+    }
+}
+
+#[test]
+fn test_run_is_the_default_command() {
+    let options = compile("prog.quest mapa.qmap");
+    assert_eq!(options.command, Command::Run);
+    assert_eq!(options.map.as_deref(), Some("mapa.qmap"));
+}
+
+#[test]
+fn test_flags_can_appear_anywhere() {
+    let options = compile("-v check --error-format=json prog.quest");
+    assert_eq!(options.command, Command::Check);
+    assert_eq!(options.error_format, ErrorFormat::Json);
+    assert_eq!(options.verbosity, Verbosity::Verbose);
+
+    let options = compile("lex prog.quest --quiet");
+    assert_eq!(options.verbosity, Verbosity::Quiet);
+    assert_eq!(options.error_format, ErrorFormat::Human);
+}
+
+#[test]
+fn test_explain_and_help() {
+    assert_eq!(parse_args(&args("--explain Q0102")), Ok(Invocation::Explain("Q0102".to_string())));
+    assert_eq!(parse_args(&args("run -h")), Ok(Invocation::Help));
+}
+
+#[test]
+fn test_usage_errors() {
+    for line in ["", "check", "--error-format=xml prog.quest", "--colour prog.quest", "check prog.quest mapa.qmap", "run a b c"] {
+        assert!(parse_args(&args(line)).is_err(), "'{}' deveria ser rejeitado", line);
+    }
+}
+
+#[test]
+fn test_file_named_like_a_command() {
+    let options = compile("check run");
+    assert_eq!(options.command, Command::Check);
+    assert_eq!(options.source, "run", "Depois do comando, o nome vira o arquivo fonte");
+}
+
+#[test]
+fn test_check_is_only_for_fmt() {
+    assert!(compile("fmt --check prog.quest").check);
+    assert!(!compile("fmt prog.quest").check);
+    assert!(parse_args(&args("check --check prog.quest")).is_err());
+}
+
+#[test]
+fn test_vm_is_only_for_run() {
+    assert!(compile("run --vm prog.quest mapa.qmap").vm);
+    assert!(!compile("run prog.quest").vm);
+    assert!(parse_args(&args("check --vm prog.quest")).is_err());
+}
+
+#[test]
+fn test_emit_is_only_for_parse() {
+    assert_eq!(compile("parse --emit=ast-json prog.quest").emit, Emit::AstJson);
+    assert_eq!(compile("parse --emit=ast prog.quest").emit, Emit::Ast);
+    assert_eq!(compile("parse prog.quest").emit, Emit::Ast);
+    assert_eq!(compile("parse --emit=ast-dot prog.quest").emit, Emit::AstDot);
+    assert_eq!(compile("parse --emit=cfg-dot prog.quest").emit, Emit::CfgDot);
+    assert!(parse_args(&args("parse --emit=tokens prog.quest")).is_err());
+    assert!(parse_args(&args("check --emit=ast-json prog.quest")).is_err());
+}
+
+#[test]
+fn test_optimize_needs_an_ast_command() {
+    for line in ["parse --optimize prog.quest", "check --optimize prog.quest", "disasm --optimize prog.quest", "run --optimize prog.quest"] {
+        assert!(compile(line).optimize, "'{}' deveria aceitar --optimize", line);
+    }
+    assert!(!compile("run prog.quest").optimize);
+    assert!(parse_args(&args("lex --optimize prog.quest")).is_err());
+    assert!(parse_args(&args("fmt --optimize prog.quest")).is_err());
+}
+
+#[test]
+fn test_exit_codes() {
+    let exit_code = |line: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_questlang-compiler"))
+            .args(args(line))
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .expect("O binário deve executar")
+            .status
+            .code()
+    };
+    let cases = [
+        ("--explain Q0001", 0),
+        ("check codigo-questlang/aninhado.quest", 0),
+        ("check codigo-questlang/erros.quest", 1),
+        ("--explain Q9999", 2),
+        ("--bogus", 2),
+        ("check nao-existe.quest", 2),
+    ];
+    for (line, expected) in cases {
+        assert_eq!(exit_code(line), Some(expected), "Código de saída incorreto para '{}'", line);
+    }
+}
@@ -0,0 +1,82 @@
+use questlang::cst::cst::{SyntaxElement, SyntaxKind, SyntaxTree};
+use questlang::cst::trivia::lex_with_trivia;
+use questlang::lexer::token::Token;
+
+#[test]
+fn test_tree_reproduces_the_source() {
+    let paths = [
+        "codigo-questlang/main.quest",
+        "codigo-questlang/aninhado.quest",
+        "codigo-questlang/big-file.quest",
+        "codigo-questlang/erros.quest",
+        "codigo-questlang/big-erros.quest",
+    ];
+    for path in paths {
+        let source = std::fs::read_to_string(path).expect("Arquivo de exemplo deve existir");
+        let (tree, _, _) = SyntaxTree::parse(&source);
+        assert_eq!(tree.text(), source, "A árvore concreta deve reproduzir {} sem perdas", path);
+    }
+}
+
+#[test]
+fn test_odd_inputs_are_lossless() {
+    for source in ["", "   ", "// só comentário", "\n\n  // x\n", "jump\t// fim", "move_up @ 12ab\r\n}", "if (hero {\n"] {
+        let (tree, _, _) = SyntaxTree::parse(source);
+        assert_eq!(tree.text(), source, "Falha para {:?}", source);
+    }
+}
+
+#[test]
+fn test_trivia_attachment() {
+    let source = "  // antes\njump  // depois\n// fim";
+    let (tokens, eof) = lex_with_trivia(source);
+    let kinds: Vec<Token> = tokens.iter().map(|token| token.kind.clone()).collect();
+    assert_eq!(kinds, vec![Token::Newline, Token::Jump, Token::Newline]);
+
+    let leading: Vec<&str> = tokens[0].leading.iter().map(|trivia| trivia.text.as_str()).collect();
+    assert_eq!(leading, vec!["  ", "// antes"], "Trivia no começo da linha é leading do primeiro token");
+
+    let trailing: Vec<&str> = tokens[1].trailing.iter().map(|trivia| trivia.text.as_str()).collect();
+    assert_eq!(trailing, vec!["  ", "// depois"], "Comentário de fim de linha é trailing do token anterior");
+    assert_eq!(tokens[1].comments().count(), 1);
+
+    assert_eq!(eof.len(), 1);
+    assert_eq!(eof[0].kind, Token::Comment);
+    assert_eq!(eof[0].text, "// fim");
+}
+
+#[test]
+fn test_nodes_follow_the_ast() {
+    let source = "if (hero) { // vivo\n    jump\n}\nlet x: int = (1 + 2) * 3";
+    let (tree, ast, errors) = SyntaxTree::parse(source);
+    assert!(!errors.has_errors());
+    assert_eq!(ast.len(), 2);
+
+    let statements: Vec<SyntaxKind> = tree.root.nodes().map(|node| node.kind).collect();
+    assert_eq!(statements, vec![SyntaxKind::If, SyntaxKind::Let]);
+
+    let if_node = tree.root.nodes().next().unwrap();
+    let if_children: Vec<SyntaxKind> = if_node.nodes().map(|node| node.kind).collect();
+    assert_eq!(if_children, vec![SyntaxKind::Identifier, SyntaxKind::Command]);
+    assert_eq!(if_node.comments()[0].text, "// vivo", "O comentário pertence ao if");
+
+    let let_node = tree.root.nodes().nth(1).unwrap();
+    let value = let_node.nodes().next().unwrap();
+    assert_eq!(value.kind, SyntaxKind::BinaryOp);
+    assert_eq!(value.text(), "(1 + 2) * 3", "Os parênteses ficam dentro do operando");
+    assert!(
+        value.children.iter().any(|child| matches!(child, SyntaxElement::Token(token) if token.kind == Token::LParen)),
+        "Parênteses são tokens do nó que os contém"
+    );
+}
+
+#[test]
+fn test_error_nodes_keep_their_tokens() {
+    let source = "let x: int = ;\njump";
+    let (tree, _, errors) = SyntaxTree::parse(source);
+    assert!(errors.has_errors());
+    let error = tree.root.nodes().next().unwrap();
+    assert_eq!(error.kind, SyntaxKind::ErrorStmt);
+    assert_eq!(error.text(), "let x: int = ;");
+    assert_eq!(tree.text(), source);
+}
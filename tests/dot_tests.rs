@@ -0,0 +1,83 @@
+use questlang::cfg::cfg::Cfg;
+use questlang::dot::{ast_to_dot, cfg_to_dot};
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::Stmt;
+use questlang::parser::parser::Parser;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    Parser::new(Lexer::new(source).tokenize(), source).parse().0
+}
+
+#[test]
+fn test_ast_dot() {
+    let ast = parse("if (!enemy) { move_up } else { let x: int = 2 }");
+    let expected = r#"digraph ast {
+    ordering=out;
+    node [shape=box, fontname="monospace"];
+    n0 [label="program", shape=box];
+    n1 [label="if", shape=box];
+    n2 [label="!", shape=ellipse];
+    n3 [label="enemy", shape=ellipse];
+    n2 -> n3;
+    n1 -> n2 [label="condition"];
+    n4 [label="move_up", shape=box];
+    n1 -> n4 [label="then"];
+    n5 [label="let x: int", shape=box];
+    n6 [label="2", shape=ellipse];
+    n5 -> n6 [label="value"];
+    n1 -> n5 [label="else"];
+    n0 -> n1;
+}
+"#;
+    assert_eq!(ast_to_dot(&ast), expected);
+}
+
+#[test]
+fn test_ast_dot_marks_errors() {
+    let dot = ast_to_dot(&parse("while ( { jump }"));
+    assert!(
+        dot.contains(r#"[label="<error>", shape=ellipse, color=red, fontcolor=red]"#),
+        "A condição inválida deve aparecer em vermelho:\n{}",
+        dot
+    );
+}
+
+#[test]
+fn test_cfg_dot() {
+    let ast = parse("let n: int = 0\nwhile (n < 2) { attack\n n = n + 1 }\nmove_up");
+    let expected = r#"digraph cfg {
+    node [shape=box, fontname="monospace"];
+    b0 [label="B0 (entry)\llet n: int = 0\l"];
+    b0 -> b1;
+    b1 [label="B1\lbranch (n < 2)\l"];
+    b1 -> b2 [label="true"];
+    b1 -> b3 [label="false"];
+    b2 [label="B2\lattack\ln = n + 1\l"];
+    b2 -> b1;
+    b3 [label="B3 (exit)\lmove_up\l"];
+}
+"#;
+    assert_eq!(cfg_to_dot(&Cfg::build(&ast)), expected);
+}
+
+#[test]
+fn test_nested_sample_is_well_formed() {
+    let source = std::fs::read_to_string("codigo-questlang/aninhado.quest").expect("Arquivo de exemplo deve existir");
+    let ast = parse(&source);
+    for (dot, prefix) in [(ast_to_dot(&ast), 'n'), (cfg_to_dot(&Cfg::build(&ast)), 'b')] {
+        // Toda aresta liga nós declarados antes dela.
+        let mut declared = Vec::new();
+        for line in dot.lines().map(str::trim).filter(|line| line.starts_with(prefix)) {
+            let (head, _) = line.split_once(' ').unwrap();
+            if let Some((_, target)) = line.split_once(" -> ") {
+                let target = target.trim_end_matches(';').split(' ').next().unwrap();
+                assert!(declared.contains(&head.to_string()), "Origem {} não declarada", head);
+                assert!(target.starts_with(prefix), "Destino inválido em '{}'", line);
+            } else {
+                declared.push(head.to_string());
+            }
+        }
+        assert!(declared.len() > 1, "O grafo de aninhado.quest deve ter vários nós");
+        assert!(dot.ends_with("}\n"));
+    }
+}
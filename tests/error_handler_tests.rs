@@ -1,6 +1,10 @@
+use questlang::error_handler::error_code::ErrorCode;
 use questlang::error_handler::error_type::ErrorType;
 use questlang::error_handler::compiler_error::CompilerError;
 use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::error_handler::json_emitter::JsonEmitter;
+use questlang::error_handler::renderer::Renderer;
+use questlang::span::Span;
 
 #[test]
 fn test_add_error() {
@@ -53,3 +57,104 @@ fn test_report() {
     // Apenas garantir que report() roda sem panicar.
     handler.report();
 }
+
+#[test]
+fn test_render_underlines_span() {
+    let source = "move_up\nif (dragon) { jump }";
+    let mut handler = ErrorHandler::new();
+    handler.add_error_at(ErrorCode::UndefinedIdentifier, "Undefined identifier 'dragon'", Span::new(12, 18), source);
+    let expected = "\
+error[Q0201]: Undefined identifier 'dragon'
+ --> main.quest:2:5
+  |
+2 | if (dragon) { jump }
+  |     ^^^^^^
+";
+    assert_eq!(handler.render(&Renderer::new(source).with_file("main.quest")), expected);
+}
+
+#[test]
+fn test_render_without_span_or_position() {
+    let source = "\tjump\n";
+    let mut handler = ErrorHandler::new();
+    handler.add_error(ErrorType::Runtime, "Execution exceeded the limit of 10 steps", 0, 0);
+    handler.add_warning("Aviso no tab", 1, 2);
+    let expected = "\
+error[Runtime]: Execution exceeded the limit of 10 steps
+
+warning: Aviso no tab
+ --> 1:2
+  |
+1 | \tjump
+  | \t^
+";
+    assert_eq!(handler.render(&Renderer::new(source)), expected, "Sem span, um único '^'; tabs são preservados");
+}
+
+#[test]
+fn test_render_with_color() {
+    let source = "hero / 0";
+    let mut handler = ErrorHandler::new();
+    handler.add_error_at(ErrorCode::DivisionByZero, "Division by zero", Span::new(0, 8), source);
+    let output = handler.render(&Renderer::new(source).with_color(true));
+    assert!(output.starts_with("\x1b[1;31merror[Q0301]\x1b[0m"), "Cabeçalho deve ser vermelho");
+    assert!(output.contains("\x1b[1;31m^^^^^^^^\x1b[0m"), "Sublinhado deve cobrir a expressão toda");
+    assert!(!handler.render(&Renderer::new(source)).contains('\x1b'), "Sem cor, nenhum código ANSI");
+}
+
+#[test]
+fn test_json_emitter_schema() {
+    let source = "move_up\nif (dragon) { jump }";
+    let mut handler = ErrorHandler::new();
+    handler.add_error_at(ErrorCode::UndefinedIdentifier, "Undefined identifier 'dragon'", Span::new(12, 18), source);
+    handler.add_warning("Linha com \"aspas\"\te tab", 1, 1);
+    let output = JsonEmitter::new(source).with_file("main.quest").emit(&handler);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            r#"{"type":"Semantic","severity":"error","code":"Q0201","message":"Undefined identifier 'dragon'","file":"main.quest","line_start":2,"column_start":5,"line_end":2,"column_end":11}"#,
+            r#"{"type":"Semantic","severity":"warning","code":null,"message":"Linha com \"aspas\"\te tab","file":"main.quest","line_start":1,"column_start":1,"line_end":1,"column_end":1}"#,
+        ],
+        "Um objeto por diagnóstico, com chaves em ordem fixa"
+    );
+}
+
+#[test]
+fn test_coded_warning() {
+    let source = "let x: int = 2 + 3";
+    let mut handler = ErrorHandler::new();
+    handler.add_coded_warning_at(ErrorCode::ConstantFolded, "Constant expression '2 + 3' was folded to 5", Span::new(13, 18), source);
+    assert!(!handler.has_errors(), "Warnings com código continuam sendo warnings");
+    let output = handler.render(&Renderer::new(source));
+    assert!(output.starts_with("warning[Q0601]: "), "O código deve aparecer no cabeçalho: {}", output);
+    let json = JsonEmitter::new(source).emit(&handler);
+    assert!(json.contains(r#""type":"Optimization","severity":"warning","code":"Q0601""#), "{}", json);
+}
+
+#[test]
+fn test_json_emitter_without_file() {
+    let mut handler = ErrorHandler::new();
+    handler.add_error(ErrorType::Runtime, "Division by zero", 0, 0);
+    let output = JsonEmitter::new("").emit(&handler);
+    assert!(output.contains(r#""file":null"#), "Sem arquivo, 'file' deve ser null");
+    assert!(JsonEmitter::new("").emit(&ErrorHandler::new()).is_empty(), "Sem diagnósticos, nenhuma linha");
+}
+
+#[test]
+fn test_error_codes_are_unique_and_explained() {
+    let mut seen = std::collections::HashSet::new();
+    for code in ErrorCode::ALL {
+        assert!(seen.insert(code.code()), "Código {} repetido", code);
+        assert!(!code.explanation().is_empty(), "Código {} sem explicação", code);
+        assert_eq!(ErrorCode::parse(code.code()), Some(code), "O código {} deve ser lido de volta", code);
+        // Todo código cai numa faixa conhecida; senão `error_type` entra em pânico.
+        code.error_type();
+    }
+    assert_eq!(ErrorCode::parse("q0102"), Some(ErrorCode::ExpectedCloseParen));
+    assert_eq!(ErrorCode::parse("Q9999"), None);
+    assert_eq!(ErrorCode::ExpectedCloseParen.error_type(), ErrorType::Syntactic);
+    assert_eq!(ErrorCode::UnknownCell.error_type(), ErrorType::Map);
+    assert_eq!(ErrorCode::StackImbalance.error_type(), ErrorType::Assembly);
+    assert_eq!(ErrorCode::LoopNeverRuns.error_type(), ErrorType::Optimization);
+}
@@ -0,0 +1,115 @@
+use questlang::formatter::{format_source, Formatter};
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::{Span, Stmt};
+use questlang::parser::parser::Parser;
+use questlang::parser::visitor::Fold;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    let (ast, errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido:\n{}", source);
+    EraseSpans.fold_stmts(ast)
+}
+
+fn format(source: &str) -> String {
+    format_source(source).unwrap_or_else(|_| panic!("Falha ao formatar:\n{}", source))
+}
+
+/// Zera os spans para comparar só a estrutura das árvores.
+struct EraseSpans;
+
+impl Fold for EraseSpans {
+    fn fold_span(&mut self, _span: Span) -> Span {
+        Span::default()
+    }
+}
+
+#[test]
+fn test_canonical_layout() {
+    let source = "move_up\nif(hero){jump}\nelse { if (enemy)   { attack } else {defend} }\nwhile (treasure) {\n\n  let steps:int=(1+2)*3\n}\nfor (let i: int = 0; i < 3; i = i + 1) { move_left }\n";
+    let expected = "\
+move_up
+if (hero) {
+    jump
+} else if (enemy) {
+    attack
+} else {
+    defend
+}
+while (treasure) {
+    let steps: int = (1 + 2) * 3
+}
+for (let i: int = 0; i < 3; i = i + 1) {
+    move_left
+}
+";
+    assert_eq!(format(source), expected);
+}
+
+#[test]
+fn test_parentheses_only_where_needed() {
+    let cases = [
+        ("((hero))", "hero\n"),
+        ("hero - (enemy - 1)", "hero - (enemy - 1)\n"),
+        ("(hero - enemy) - 1", "hero - enemy - 1\n"),
+        ("!(hero < 3) && (enemy || trap)", "!(hero < 3) && (enemy || trap)\n"),
+        ("-(hero * 2) + -(-1)", "-(hero * 2) + --1\n"),
+    ];
+    for (source, expected) in cases {
+        assert_eq!(format(source), expected, "Formatação incorreta para '{}'", source);
+    }
+}
+
+#[test]
+fn test_format_round_trips_sample_programs() {
+    for path in ["codigo-questlang/main.quest", "codigo-questlang/aninhado.quest", "codigo-questlang/big-file.quest"] {
+        let source = std::fs::read_to_string(path).expect("Arquivo de exemplo deve existir");
+        let formatted = format(&source);
+        assert_eq!(parse(&formatted), parse(&source), "parse(fmt(x)) deve ser igual a parse(x) para {}", path);
+        assert_eq!(format(&formatted), formatted, "Formatar duas vezes não deve mudar nada em {}", path);
+    }
+}
+
+#[test]
+fn test_empty_blocks_and_program() {
+    assert_eq!(format(""), "");
+    assert_eq!(format("while (enemy) {}"), "while (enemy) {\n}\n");
+    assert_eq!(format("if (hero) {} else {}"), "if (hero) {\n}\n", "Else vazio é omitido");
+}
+
+#[test]
+fn test_invalid_programs_are_not_formatted() {
+    let errors = format_source("if (hero { jump }").expect_err("Programa inválido não deve ser formatado");
+    assert!(errors.has_errors());
+}
+
+#[test]
+fn test_formatter_can_be_reused() {
+    let ast = parse("jump");
+    let mut formatter = Formatter::new();
+    assert_eq!(formatter.format(&ast), "jump\n");
+    assert_eq!(formatter.format(&ast), "jump\n", "Chamadas seguidas não acumulam linhas");
+}
+
+#[test]
+fn test_comments_are_kept() {
+    let source = "// programa\n\nmove_up   // primeiro\nif (hero) {  // vivo?\n  // só pula\n  jump\n  // fim do then\n} else {\nattack // ataca\n} // fim do if\nwhile (enemy) { defend }\n// último\n";
+    let expected = "\
+// programa
+move_up // primeiro
+if (hero) { // vivo?
+    // só pula
+    jump
+    // fim do then
+} else {
+    attack // ataca
+} // fim do if
+while (enemy) {
+    defend
+}
+// último
+";
+    let formatted = format(source);
+    assert_eq!(formatted, expected);
+    assert_eq!(format(&formatted), formatted, "Formatar duas vezes não deve mudar nada");
+    assert_eq!(parse(&formatted), parse(source));
+}
@@ -2,7 +2,7 @@ use questlang::lexer::lexer::Lexer;
 use questlang::parser::parser::Parser;
 use questlang::pretty_print::PrettyPrinter;
 use questlang::symbol_table::symbol_table::SymbolTable;
-use questlang::symbol_table::symbol_type::SymbolType;
+use questlang::semantic::analyzer::SemanticAnalyzer;
 use questlang::parser::ast::Stmt;
 
 fn run_full_compilation(source: &str) -> (Vec<questlang::parser::ast::Stmt>, questlang::error_handler::error_handler::ErrorHandler, String, SymbolTable) {
@@ -14,19 +14,7 @@ fn run_full_compilation(source: &str) -> (Vec<questlang::parser::ast::Stmt>, que
     let mut printer = PrettyPrinter::new();
     let pretty_output = printer.print_stmts(&ast);
 
-    let mut sym_table = SymbolTable::new();
-    if source.contains("hero") {
-        sym_table.insert("hero", SymbolType::Integer, 1, 1);
-    }
-    if source.contains("enemy") {
-        sym_table.insert("enemy", SymbolType::Boolean, 1, 1);
-    }
-    if source.contains("treasure") {
-        sym_table.insert("treasure", SymbolType::Integer, 1, 1);
-    }
-    if source.contains("trap") {
-        sym_table.insert("trap", SymbolType::Boolean, 1, 1);
-    }
+    let (sym_table, _semantic_errors) = SemanticAnalyzer::new(source).analyze(&ast);
     (ast, errors, pretty_output, sym_table)
 }
 
@@ -146,10 +134,7 @@ fn test_expression_statement() {
     let (ast, errors, pretty_output, _sym_table) = run_full_compilation(source);
     assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos em expression statement");
 
-    let expr_stmt_count = ast.iter().filter(|stmt| match stmt {
-        Stmt::ExprStmt(_) => true,
-        _ => false,
-    }).count();
+    let expr_stmt_count = ast.iter().filter(|stmt| matches!(stmt, Stmt::ExprStmt(_))).count();
     assert!(expr_stmt_count >= 1, "Deve haver pelo menos um expression statement na AST");
     assert!(pretty_output.contains("Expr:"), "Pretty print deve indicar 'Expr:'");
 }
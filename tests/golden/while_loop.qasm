@@ -0,0 +1,30 @@
+.slot steps
+; 1 | let steps: int = 3
+0000  push_int 3
+0001  init 0 int              ; steps
+; 2 | while (steps > 0 && !enemy) {
+0002  load 0                  ; steps
+0003  push_int 0
+0004  gt
+0005  jump_if_false 11
+0006  load_builtin enemy
+0007  not
+0008  jump_if_false 11
+0009  push_bool true
+0010  jump 12
+0011  push_bool false
+0012  jump_if_false 20
+0013  tick
+; 3 | move_right
+0014  command move_right
+; 4 | steps = steps - 1
+0015  load 0                  ; steps
+0016  push_int 1
+0017  sub
+0018  store 0                 ; steps
+; 2 | while (steps > 0 && !enemy) {
+0019  jump 2
+; 6 | if (ghost) { jump }
+0020  fail Q0305 "Undefined identifier 'ghost'"
+0021  jump_if_false 23
+0022  command jump
@@ -0,0 +1,200 @@
+use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::interpreter::interpreter::Interpreter;
+use questlang::interpreter::trace::Trace;
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::{ActionCommand, Command, MoveCommand};
+use questlang::parser::parser::Parser;
+use questlang::world::position::Position;
+use questlang::world::world::{Outcome, World, DEFAULT_HEALTH, PREDEFINED_SYMBOLS, TRAP_DAMAGE};
+
+/// Função auxiliar que faz o parse da fonte e executa o programa no mundo dado.
+fn run(source: &str, world: World) -> (Trace, ErrorHandler) {
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize();
+    let (ast, errors) = Parser::new(tokens, source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido");
+    Interpreter::new(world).with_source(source).run(&ast)
+}
+
+#[test]
+fn test_move_commands_change_position() {
+    let (trace, errors) = run("move_right\nmove_down\nmove_down", World::new(5, 5));
+    assert!(!errors.has_errors(), "Não devem ocorrer erros de execução");
+    assert_eq!(
+        trace.positions(),
+        vec![Position::new(1, 0), Position::new(1, 1), Position::new(1, 2)],
+        "O herói deve andar uma célula por comando"
+    );
+    assert_eq!(trace.world.hero.position, Position::new(1, 2));
+}
+
+#[test]
+fn test_walls_and_bounds_block_movement() {
+    let mut world = World::new(3, 3);
+    world.add_wall(Position::new(1, 0));
+    let (trace, _) = run("move_up\nmove_right", world);
+    let outcomes: Vec<Outcome> = trace.events.iter().map(|e| e.outcome.clone()).collect();
+    assert_eq!(outcomes, vec![Outcome::Blocked, Outcome::Blocked], "Borda e parede devem bloquear o herói");
+    assert_eq!(trace.world.hero.position, Position::new(0, 0), "O herói não deve sair do lugar");
+}
+
+#[test]
+fn test_attack_defeats_enemy_ahead() {
+    let mut world = World::new(5, 1);
+    world.add_enemy(Position::new(1, 0));
+    let (trace, errors) = run("while (enemy) { attack }\nmove_right", world);
+    assert!(!errors.has_errors());
+    assert_eq!(trace.events[0].outcome, Outcome::EnemyDefeated(Position::new(1, 0)));
+    assert!(trace.world.enemies.is_empty(), "O inimigo deve ser removido do mundo");
+    assert_eq!(trace.world.hero.position, Position::new(1, 0), "Sem inimigo, o caminho fica livre");
+}
+
+#[test]
+fn test_enemy_damage_and_defend() {
+    let mut world = World::new(3, 1);
+    world.add_enemy(Position::new(1, 0));
+    let (trace, _) = run("defend\njump", world);
+    assert_eq!(trace.events[0].outcome, Outcome::Defended);
+    assert_eq!(trace.events[0].health, DEFAULT_HEALTH, "Defender deve evitar o dano");
+    assert_eq!(trace.events[1].health, DEFAULT_HEALTH - 1, "Sem defesa, o inimigo adjacente causa dano");
+}
+
+#[test]
+fn test_treasure_is_collected() {
+    let mut world = World::new(4, 1);
+    world.add_treasure(Position::new(2, 0));
+    let (trace, errors) = run("while (treasure) { move_right }", world);
+    assert!(!errors.has_errors());
+    assert_eq!(trace.events.last().unwrap().outcome, Outcome::TreasureCollected);
+    assert_eq!(trace.world.hero.treasures, 1);
+}
+
+#[test]
+fn test_jump_over_trap() {
+    let mut world = World::new(5, 1);
+    world.add_trap(Position::new(1, 0));
+    world.add_trap(Position::new(3, 0));
+    let (trace, _) = run("if (trap) { jump } else { move_right }\nmove_right", world);
+    assert_eq!(
+        trace.commands(),
+        vec![Command::Action(ActionCommand::Jump), Command::Move(MoveCommand::MoveRight)]
+    );
+    assert_eq!(trace.events[0].position, Position::new(2, 0), "O pulo deve passar por cima da armadilha");
+    assert_eq!(trace.events[1].outcome, Outcome::TrapTriggered);
+    assert_eq!(trace.world.hero.health, DEFAULT_HEALTH - TRAP_DAMAGE);
+}
+
+#[test]
+fn test_defeated_hero_stops_execution() {
+    let mut world = World::new(3, 3);
+    world.hero.health = 1;
+    world.add_enemy(Position::new(1, 1));
+    let (trace, errors) = run("move_down\nmove_down\nmove_down", world);
+    assert!(!errors.has_errors(), "Derrota não é um erro de execução");
+    assert_eq!(trace.events.len(), 1, "A execução deve parar quando o herói é derrotado");
+    assert!(trace.world.hero_defeated());
+}
+
+#[test]
+fn test_step_limit_stops_infinite_loop() {
+    let source = "while (hero) { defend }";
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize();
+    let (ast, _) = Parser::new(tokens, source).parse();
+    let (trace, errors) = Interpreter::new(World::default()).with_max_steps(50).run(&ast);
+    assert!(errors.has_errors(), "Laço infinito deve gerar erro de execução");
+    assert!(trace.events.len() < 50);
+}
+
+#[test]
+fn test_boolean_arithmetic_is_runtime_error() {
+    let (_, errors) = run("enemy - 3", World::default());
+    assert!(errors.has_errors(), "Aritmética com booleanos deve falhar na execução");
+}
+
+#[test]
+fn test_arithmetic_and_logic() {
+    // hero - 10 + 7 * 2 / 7 vale 2; !enemy é verdadeiro sem inimigo à frente
+    let source = "if (hero - 10 + 7 * 2 / 7 && !enemy) { move_right attack } else { defend }";
+    let mut world = World::new(5, 1);
+    world.add_enemy(Position::new(2, 0));
+    let (trace, errors) = run(source, world);
+    assert!(!errors.has_errors(), "Não devem ocorrer erros de execução");
+    assert_eq!(trace.events[1].outcome, Outcome::EnemyDefeated(Position::new(2, 0)));
+}
+
+#[test]
+fn test_division_by_zero_is_runtime_error() {
+    let (_, errors) = run("hero / 0", World::default());
+    assert!(errors.has_errors(), "Divisão por zero deve falhar na execução");
+}
+
+#[test]
+fn test_runtime_errors_point_at_the_failing_node() {
+    let (_, errors) = run("move_right
+if (hero) {
+  jump
+  hero + 10 / (hero - hero)
+}", World::new(5, 5));
+    let error = &errors.errors()[0];
+    assert_eq!(error.message, "Division by zero");
+    assert_eq!((error.line, error.column), (4, 10), "O erro deve apontar para a divisão");
+}
+
+#[test]
+fn test_comparison_conditions() {
+    let mut world = World::new(6, 1);
+    world.add_treasure(Position::new(2, 0));
+    world.add_treasure(Position::new(4, 0));
+    let (trace, errors) = run("while (treasure > 0 && trap == enemy) { move_right }", world);
+    assert!(!errors.has_errors(), "Não devem ocorrer erros de execução");
+    assert_eq!(trace.world.hero.position, Position::new(4, 0), "O laço deve parar no último tesouro");
+}
+
+#[test]
+fn test_comparing_different_types_is_runtime_error() {
+    let (_, errors) = run("hero == enemy", World::default());
+    assert!(errors.has_errors(), "Comparar inteiro com booleano deve falhar na execução");
+}
+
+#[test]
+fn test_grouping_changes_evaluation() {
+    // A vida inicial é 10: 2 * (3 + 2) vale 10, mas 2 * 3 + 2 valeria 8
+    let (trace, _) = run("if (hero == 2 * (3 + 2)) { jump } else { defend }", World::default());
+    assert_eq!(trace.commands(), vec![Command::Action(ActionCommand::Jump)]);
+}
+
+#[test]
+fn test_counting_loop_terminates() {
+    let source = "let moves: int = 0\nfor (let i: int = 0; i < 3; i = i + 1) { move_right\nmoves = moves + 1 }\nwhile (moves > 0) { move_left\nmoves = moves - 1 }";
+    let (trace, errors) = run(source, World::new(5, 1));
+    assert!(!errors.has_errors(), "Não devem ocorrer erros de execução");
+    assert_eq!(trace.events.len(), 6, "Três passos para a direita e três de volta");
+    assert_eq!(trace.world.hero.position, Position::new(0, 0));
+}
+
+#[test]
+fn test_variable_type_is_enforced_at_runtime() {
+    let (_, errors) = run("let steps: int = 1\nsteps = enemy", World::default());
+    assert!(errors.has_errors(), "Atribuir booleano a variável inteira deve falhar na execução");
+}
+
+#[test]
+fn test_else_if_chain_picks_first_true_branch() {
+    let mut world = World::new(3, 1);
+    world.add_trap(Position::new(1, 0));
+    let source = "if (enemy) { attack } else if (trap) { jump } else { defend }\nif (enemy) { attack }";
+    let (trace, _) = run(source, world);
+    assert_eq!(trace.commands(), vec![Command::Action(ActionCommand::Jump)], "Só o ramo do 'trap' deve executar");
+}
+
+#[test]
+fn test_every_predefined_symbol_has_a_value() {
+    // Analisador, interpretador e VM leem os mesmos nomes; o World dá o valor de todos.
+    let world = World::default();
+    for (name, symbol_type) in PREDEFINED_SYMBOLS {
+        let value = world.predefined(name).unwrap_or_else(|| panic!("'{}' deve ter valor no mundo", name));
+        assert_eq!(value.symbol_type(), symbol_type, "Tipo incorreto para '{}'", name);
+    }
+    assert!(world.predefined("dragon").is_none());
+}
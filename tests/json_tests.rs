@@ -0,0 +1,36 @@
+use questlang::json::Json;
+
+#[test]
+fn test_parse_values() {
+    let json = Json::parse(" {\"a\": [1, -20, true, false, null], \"b\": {}, \"c\": \"x\\\"y\\n\\u00e9\\ud83d\\ude00\"} ").unwrap();
+    assert_eq!(
+        json,
+        Json::object(vec![
+            (
+                "a",
+                Json::Array(vec![Json::Number(1), Json::Number(-20), Json::Bool(true), Json::Bool(false), Json::Null])
+            ),
+            ("b", Json::Object(vec![])),
+            ("c", Json::string("x\"y\né😀")),
+        ])
+    );
+    assert_eq!(json.get("c").and_then(Json::as_str), Some("x\"y\né😀"));
+    assert_eq!(json.get("a").and_then(Json::as_array).map(<[Json]>::len), Some(5));
+    assert_eq!(json.get("z"), None);
+}
+
+#[test]
+fn test_display_round_trip() {
+    let json = Json::object(vec![
+        ("texto", Json::string("aspas \" barra \\ tab \t controle \u{1}")),
+        ("lista", Json::Array(vec![Json::Number(i64::MIN), Json::Null])),
+    ]);
+    assert_eq!(Json::parse(&json.to_string()), Ok(json), "Json::parse deve ler o que Display escreve");
+}
+
+#[test]
+fn test_parse_errors() {
+    for text in ["", "[1,]", "{\"a\" 1}", "{a: 1}", "\"aberta", "1.5", "1e3", "tru", "[1] 2", "\"\\x\"", "\"\\ud83d\"", "\"\n\""] {
+        assert!(Json::parse(text).is_err(), "{:?} deveria ser rejeitado", text);
+    }
+}
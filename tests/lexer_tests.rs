@@ -206,4 +206,87 @@ fn test_mixed_with_invalid_tokens() {
         "Falha ao tokenizar código misto com tokens inválidos: {}",
         input
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_comparison_operators() {
+    let input = "< > <= >= == != !";
+    let tokens = lex(input);
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Less,
+            Token::Greater,
+            Token::LessEqual,
+            Token::GreaterEqual,
+            Token::EqualEqual,
+            Token::NotEqual,
+            Token::LogicalNot,
+        ],
+        "Falha ao tokenizar operadores de comparação: {}",
+        input
+    );
+}
+
+#[test]
+fn test_declaration_tokens() {
+    let input = "let steps: int = 3\nsteps = steps - 1";
+    let tokens = lex(input);
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Let,
+            Token::Identifier,
+            Token::Colon,
+            Token::IntType,
+            Token::Assign,
+            Token::Number,
+            Token::Newline,
+            Token::Identifier,
+            Token::Assign,
+            Token::Identifier,
+            Token::Minus,
+            Token::Number,
+        ],
+        "Falha ao tokenizar declaração e atribuição: {}",
+        input
+    );
+}
+
+#[test]
+fn test_lexical_errors_are_reported_with_spans() {
+    let input = "move_up @ attack\n123abc 99999999999 jump";
+    let (tokens, errors) = Lexer::new(input).lex();
+    assert_eq!(tokens.last().map(|(token, _, _)| token.clone()), Some(Token::Jump), "O lexer deve continuar após os erros");
+
+    let report: Vec<(String, String, &str)> = errors
+        .errors()
+        .iter()
+        .map(|e| {
+            let span = e.span.expect("Erro léxico deve ter span");
+            (e.code.unwrap().to_string(), e.to_string(), &input[span.start..span.end])
+        })
+        .collect();
+    assert_eq!(
+        report,
+        vec![
+            ("Q0001".to_string(), "[Lexical Error] Unexpected character '@' (Linha 1, Coluna 9)".to_string(), "@"),
+            (
+                "Q0002".to_string(),
+                "[Lexical Error] Malformed number '123abc': identifiers cannot start with a digit (Linha 2, Coluna 1)".to_string(),
+                "123abc"
+            ),
+            (
+                "Q0003".to_string(),
+                "[Lexical Error] Integer literal '99999999999' does not fit in a 32-bit integer (Linha 2, Coluna 8)".to_string(),
+                "99999999999"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_valid_input_has_no_lexical_errors() {
+    let (_, errors) = Lexer::new("let steps: int = 3 // comentário com @ e #\nsteps = steps - 1").lex();
+    assert!(!errors.has_errors(), "Comentários podem conter qualquer caractere");
+}
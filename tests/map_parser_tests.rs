@@ -0,0 +1,89 @@
+use questlang::world::map_parser::MapParser;
+use questlang::world::position::Position;
+use questlang::world::world::PREDEFINED_SYMBOLS;
+use questlang::symbol_table::symbol_type::SymbolType;
+
+const VALID_MAP: &str = "\
+// Mapa de teste
+name: Corredor
+health: 5
+---
+#####
+#H.E#
+#^.T#
+#####
+";
+
+#[test]
+fn test_valid_map() {
+    let (world, errors) = MapParser::new(VALID_MAP).parse();
+    assert!(!errors.has_errors(), "Mapa válido não deve gerar erros");
+    let world = world.expect("Mapa válido deve produzir um mundo");
+
+    assert_eq!(world.name, "Corredor");
+    assert_eq!((world.width, world.height), (5, 4), "Dimensões devem vir do grid");
+    assert_eq!(world.hero.health, 5, "A vida deve vir do cabeçalho");
+    assert_eq!(world.hero.position, Position::new(1, 1));
+    assert!(world.enemies.contains(&Position::new(3, 1)));
+    assert!(world.traps.contains(&Position::new(1, 2)));
+    assert!(world.treasures.contains(&Position::new(3, 2)));
+    assert_eq!(world.walls.len(), 14, "A borda deve ser formada por paredes");
+}
+
+#[test]
+fn test_missing_separator() {
+    let (world, errors) = MapParser::new("name: Sem grid\n").parse();
+    assert!(world.is_none(), "Sem '---' não há mundo");
+    assert!(errors.has_errors(), "Falta do separador deve gerar erro");
+}
+
+#[test]
+fn test_unknown_header_key_and_bad_health() {
+    let source = "color: azul\nhealth: -3\n---\nH.\n";
+    let (world, errors) = MapParser::new(source).parse();
+    assert!(world.is_none());
+    assert!(errors.has_errors(), "Chave desconhecida e vida inválida devem gerar erros");
+}
+
+#[test]
+fn test_ragged_rows() {
+    let (world, errors) = MapParser::new("---\nH..\n..\n").parse();
+    assert!(world.is_none());
+    assert!(errors.has_errors(), "Linhas com larguras diferentes devem gerar erro");
+}
+
+#[test]
+fn test_ragged_row_cells_are_still_read() {
+    // O 'H' numa linha irregular ainda conta: só a linha irregular é reportada.
+    let (world, errors) = MapParser::new("---\n...\n..H.\n").parse();
+    assert!(world.is_none());
+    let codes: Vec<_> = errors.errors().iter().filter_map(|error| error.code.map(|code| code.code())).collect();
+    assert_eq!(codes, ["Q0406"], "Não deve haver erro de herói faltando");
+
+    let (_, errors) = MapParser::new("---\nH..\n...X\n").parse();
+    let codes: Vec<_> = errors.errors().iter().filter_map(|error| error.code.map(|code| code.code())).collect();
+    assert_eq!(codes, ["Q0406", "Q0407"], "Células além da largura também são validadas");
+}
+
+#[test]
+fn test_hero_count() {
+    let (_, no_hero) = MapParser::new("---\n...\n").parse();
+    assert!(no_hero.has_errors(), "Mapa sem herói deve gerar erro");
+
+    let (_, two_heroes) = MapParser::new("---\nH.H\n").parse();
+    assert!(two_heroes.has_errors(), "Mapa com dois heróis deve gerar erro");
+}
+
+#[test]
+fn test_unknown_cell() {
+    let (world, errors) = MapParser::new("---\nH?.\n").parse();
+    assert!(world.is_none());
+    assert!(errors.has_errors(), "Célula desconhecida deve gerar erro");
+}
+
+#[test]
+fn test_predefined_symbols() {
+    let names: Vec<&str> = PREDEFINED_SYMBOLS.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, vec!["hero", "enemy", "treasure", "trap"]);
+    assert!(PREDEFINED_SYMBOLS.contains(&("enemy", SymbolType::Boolean)), "'enemy' deve ser booleano");
+}
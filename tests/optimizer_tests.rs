@@ -0,0 +1,173 @@
+use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::formatter::Formatter;
+use questlang::interpreter::interpreter::Interpreter;
+use questlang::lexer::lexer::Lexer;
+use questlang::optimizer::optimizer::Optimizer;
+use questlang::parser::ast::Stmt;
+use questlang::parser::parser::Parser;
+use questlang::world::map_parser::MapParser;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    let (ast, errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido:\n{}", source);
+    ast
+}
+
+/// Programa otimizado, escrito no formato canônico, e as mensagens dos warnings.
+fn optimize(source: &str) -> (String, Vec<String>) {
+    let (ast, warnings) = Optimizer::new(source).optimize(parse(source));
+    assert!(!warnings.has_errors(), "O otimizador só produz warnings");
+    let messages = warnings.warnings().iter().map(|warning| warning.message.clone()).collect();
+    (Formatter::new().format(&ast), messages)
+}
+
+fn messages(errors: &ErrorHandler) -> Vec<String> {
+    errors.errors().iter().map(|e| e.to_string()).collect()
+}
+
+#[test]
+fn test_folds_arithmetic_on_numbers() {
+    let (output, warnings) = optimize("let x: int = 2 * 3 + 1\nlet y: int = -(4 - 10) / 2\nlet z: int = -5\n");
+    assert_eq!(output, "let x: int = 7\nlet y: int = 3\nlet z: int = -5\n");
+    assert_eq!(
+        warnings,
+        ["Constant expression '2 * 3 + 1' was folded to 7", "Constant expression '-(4 - 10) / 2' was folded to 3"],
+        "Um warning por expressão constante; literais negativos não geram aviso"
+    );
+}
+
+#[test]
+fn test_folds_inside_larger_expressions() {
+    let (output, warnings) = optimize("let x: int = hero * (2 + 3)\nif (enemy && treasure > 10 - 8) { jump }\n");
+    assert_eq!(output, "let x: int = hero * 5\nif (enemy && treasure > 2) {\n    jump\n}\n");
+    assert_eq!(warnings.len(), 2);
+}
+
+#[test]
+fn test_combines_constants_in_the_same_direction() {
+    let (output, warnings) = optimize("let a: int = hero + 1 + 2\nlet b: int = hero - 1 - 2\nlet c: int = hero + 1 - -2\n");
+    assert_eq!(output, "let a: int = hero + 3\nlet b: int = hero - 3\nlet c: int = hero + 3\n");
+    assert_eq!(warnings[0], "Constants in 'hero + 1 + 2' were combined into 'hero + 3'");
+
+    // `hero + 10 - 5` pode estourar em `hero + 10` sem que `hero + 5` estoure.
+    let (output, warnings) = optimize("if (hero + 10 - 5) { jump }\n");
+    assert_eq!(output, "if (hero + 10 - 5) {\n    jump\n}\n");
+    assert!(warnings.is_empty(), "Constantes em direções opostas não são juntadas");
+}
+
+#[test]
+fn test_keeps_expressions_that_fail_or_are_boolean() {
+    let source = "let a: int = 1 / 0\nlet b: int = 2147483647 + 1\nlet c: bool = 1 < 2\nlet d: bool = !0\n";
+    let (output, warnings) = optimize(source);
+    assert_eq!(output, source, "Contas que falham e resultados booleanos ficam como estão");
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_constant_if_keeps_the_live_branch() {
+    let (output, warnings) = optimize("if (1) { jump } else { attack }\nif (2 - 2) { defend } else { move_up }\nif (0) { move_down }\n");
+    assert_eq!(output, "jump\nmove_up\n");
+    assert_eq!(
+        warnings,
+        [
+            "Condition is always true; the 'if' was replaced by its body",
+            "Constant expression '2 - 2' was folded to 0",
+            "Condition is always false; the 'if' was replaced by its 'else' branch",
+            "Condition is always false; the 'if' was removed",
+        ]
+    );
+}
+
+#[test]
+fn test_else_if_chain_is_simplified() {
+    let (output, _) = optimize("if (enemy) { attack } else if (0) { jump } else if (trap) { defend }\n");
+    assert_eq!(output, "if (enemy) {\n    attack\n} else if (trap) {\n    defend\n}\n");
+}
+
+#[test]
+fn test_branch_with_declarations_keeps_its_scope() {
+    let (output, warnings) = optimize("if (1) { let x: int = 1\njump } else { attack }\nlet x: bool = enemy\n");
+    assert_eq!(output, "if (1) {\n    let x: int = 1\n    jump\n}\nlet x: bool = enemy\n");
+    assert_eq!(warnings, ["Condition is always true; the branch that never runs was removed"]);
+}
+
+#[test]
+fn test_loops_that_never_run_are_removed() {
+    let source = "\
+let i: int = 5
+while (0) { attack }
+for (let j: int = 0; 0; j = j + 1) { jump }
+for (i = 0; 3 - 3; i = i + 1) { jump }
+for (let k: int = hero * 2; 0; k = k + 1) { jump }
+while (1) { defend }
+";
+    let (output, warnings) = optimize(source);
+    assert_eq!(
+        output,
+        "let i: int = 5\ni = 0\nhero * 2\nwhile (1) {\n    defend\n}\n",
+        "O init de um for continua executando uma vez"
+    );
+    assert_eq!(warnings.iter().filter(|msg| msg.contains("loop was removed")).count(), 4);
+}
+
+#[test]
+fn test_comparisons_and_logic_make_constant_conditions() {
+    let source = "if (3 > 2) { jump } else { attack }\nwhile (!1) { defend }\nif (1 && 0) { move_up }\nif (enemy || 2 * 3 > 5) { move_down }\n";
+    let (output, warnings) = optimize(source);
+    assert_eq!(output, "jump\nif (enemy || 6 > 5) {\n    move_down\n}\n", "Só a condição com variável fica");
+    assert_eq!(
+        warnings,
+        [
+            "Condition is always true; the 'if' was replaced by its body",
+            "Loop condition is always false; the 'while' loop was removed",
+            "Condition is always false; the 'if' was removed",
+            "Constant expression '2 * 3' was folded to 6",
+        ]
+    );
+}
+
+#[test]
+fn test_warnings_point_to_the_condition() {
+    let source = "move_up\nwhile (0) { attack }";
+    let (_, warnings) = Optimizer::new(source).optimize(parse(source));
+    let warning = &warnings.warnings()[0];
+    assert_eq!((warning.line, warning.column), (2, 8));
+}
+
+#[test]
+fn test_warnings_carry_codes() {
+    let source = "let x: int = 2 * 3\nlet y: int = hero + 1 + 2\nif (1) { jump }\nwhile (0) { attack }";
+    let (_, warnings) = Optimizer::new(source).optimize(parse(source));
+    let codes: Vec<_> = warnings.warnings().iter().map(|warning| warning.code.map(|code| code.code())).collect();
+    assert_eq!(codes, [Some("Q0601"), Some("Q0602"), Some("Q0603"), Some("Q0604")], "Cada reescrita tem o seu código");
+}
+
+#[test]
+fn test_behavior_is_preserved() {
+    let map = std::fs::read_to_string("codigo-questlang/mapa.qmap").expect("Mapa de exemplo deve existir");
+    let (world, _) = MapParser::new(&map).parse();
+    let world = world.expect("O mapa de exemplo deve ser válido");
+    let programs = [
+        std::fs::read_to_string("codigo-questlang/main.quest").unwrap(),
+        std::fs::read_to_string("codigo-questlang/aninhado.quest").unwrap(),
+        "let n: int = 2 * 2\nwhile (n > 10 - 10) { move_right\nn = n - 1 + 0 }\nif (1 + 1) { jump }\nlet x: int = n / (3 - 3)\n".to_string(),
+        "for (let i: int = 0; i < 2 + 1; i = i + 1) { if (0) { attack } else { move_down } }\nlet y: int = 2147483647 - 1 + 2\n".to_string(),
+    ];
+    for source in &programs {
+        let ast = parse(source);
+        let (expected_trace, expected_errors) = Interpreter::new(world.clone()).with_source(source).run(&ast);
+        let (optimized, _) = Optimizer::new(source).optimize(ast);
+        let (trace, errors) = Interpreter::new(world.clone()).with_source(source).run(&optimized);
+        assert_eq!(trace, expected_trace, "O programa otimizado deve fazer o mesmo:\n{}", source);
+        assert_eq!(messages(&errors), messages(&expected_errors), "Os erros de execução devem ser os mesmos:\n{}", source);
+    }
+}
+
+#[test]
+fn test_program_without_constants_is_untouched() {
+    let source = std::fs::read_to_string("codigo-questlang/big-file.quest").unwrap();
+    let ast = parse(&source);
+    let (optimized, warnings) = Optimizer::new(&source).optimize(ast.clone());
+    assert_eq!(optimized, ast);
+    assert!(warnings.warnings().is_empty());
+}
@@ -1,7 +1,9 @@
-use questlang::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr};
+use questlang::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr, BinOp, UnaryOp, Span};
 use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::error_handler::error_type::ErrorType;
 use questlang::lexer::lexer::Lexer;
 use questlang::parser::parser::Parser;
+use questlang::symbol_table::symbol_type::SymbolType;
 
 /// Função auxiliar que processa a fonte e retorna a AST e o ErrorHandler.
 fn parse_source(source: &str) -> (Vec<Stmt>, ErrorHandler) {
@@ -19,7 +21,7 @@ fn test_command_statement() {
     assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
     assert_eq!(ast.len(), 1, "Deve haver 1 statement");
     match &ast[0] {
-        Stmt::Command(cmd) => match cmd {
+        Stmt::Command(cmd, _) => match cmd {
             Command::Move(mv) => assert_eq!(mv, &MoveCommand::MoveUp, "Deve ser move_up"),
             _ => panic!("Esperado comando de movimento"),
         },
@@ -35,16 +37,16 @@ fn test_if_statement() {
     assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
     assert_eq!(ast.len(), 1, "Deve haver 1 statement");
     match &ast[0] {
-        Stmt::IfStmt { condition, then_branch, else_branch } => {
+        Stmt::IfStmt { condition, then_branch, else_branch, .. } => {
             // Condição deve ser um identificador "hero"
             match condition {
-                Expr::Identifier(s) => assert_eq!(s, "hero", "Condição deve ser 'hero'"),
+                Expr::Identifier(s, _) => assert_eq!(s, "hero", "Condição deve ser 'hero'"),
                 _ => panic!("Condição do if deve ser um identificador"),
             }
             // then_branch: espera um comando: move_left
             assert_eq!(then_branch.len(), 1, "Then branch deve ter 1 statement");
             match &then_branch[0] {
-                Stmt::Command(cmd) => match cmd {
+                Stmt::Command(cmd, _) => match cmd {
                     Command::Move(mv) => assert_eq!(mv, &MoveCommand::MoveLeft, "Then branch deve ser move_left"),
                     _ => panic!("Then branch deve ser um comando de movimento"),
                 },
@@ -53,7 +55,7 @@ fn test_if_statement() {
             // else_branch: espera um comando: move_right
             assert_eq!(else_branch.len(), 1, "Else branch deve ter 1 statement");
             match &else_branch[0] {
-                Stmt::Command(cmd) => match cmd {
+                Stmt::Command(cmd, _) => match cmd {
                     Command::Move(mv) => assert_eq!(mv, &MoveCommand::MoveRight, "Else branch deve ser move_right"),
                     _ => panic!("Else branch deve ser um comando de movimento"),
                 },
@@ -72,16 +74,16 @@ fn test_while_statement() {
     assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
     assert_eq!(ast.len(), 1, "Deve haver 1 statement");
     match &ast[0] {
-        Stmt::WhileStmt { condition, body } => {
+        Stmt::WhileStmt { condition, body, .. } => {
             // Condição deve ser "enemy"
             match condition {
-                Expr::Identifier(s) => assert_eq!(s, "enemy", "Condição deve ser 'enemy'"),
+                Expr::Identifier(s, _) => assert_eq!(s, "enemy", "Condição deve ser 'enemy'"),
                 _ => panic!("Condição do while deve ser um identificador"),
             }
             // Body: espera comando: jump
             assert_eq!(body.len(), 1, "Body deve ter 1 statement");
             match &body[0] {
-                Stmt::Command(cmd) => match cmd {
+                Stmt::Command(cmd, _) => match cmd {
                     Command::Action(act) => assert_eq!(act, &ActionCommand::Jump, "Body deve ser jump"),
                     _ => panic!("Body do while deve ser um comando de ação"),
                 },
@@ -100,24 +102,24 @@ fn test_for_statement() {
     assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
     assert_eq!(ast.len(), 1, "Deve haver 1 statement");
     match &ast[0] {
-        Stmt::ForStmt { init, condition, update, body } => {
+        Stmt::ForStmt { init, condition, update, body, .. } => {
             // Verifica init, condition e update
-            match init {
-                Expr::Identifier(s) => assert_eq!(s, "hero", "Init deve ser 'hero'"),
+            match init.as_ref() {
+                Stmt::ExprStmt(Expr::Identifier(s, _)) => assert_eq!(s, "hero", "Init deve ser 'hero'"),
                 _ => panic!("Init do for deve ser um identificador"),
             }
             match condition {
-                Expr::Identifier(s) => assert_eq!(s, "enemy", "Condition deve ser 'enemy'"),
+                Expr::Identifier(s, _) => assert_eq!(s, "enemy", "Condition deve ser 'enemy'"),
                 _ => panic!("Condition do for deve ser um identificador"),
             }
-            match update {
-                Expr::Identifier(s) => assert_eq!(s, "treasure", "Update deve ser 'treasure'"),
+            match update.as_ref() {
+                Stmt::ExprStmt(Expr::Identifier(s, _)) => assert_eq!(s, "treasure", "Update deve ser 'treasure'"),
                 _ => panic!("Update do for deve ser um identificador"),
             }
             // Body: espera comando: defend
             assert_eq!(body.len(), 1, "Body deve ter 1 statement");
             match &body[0] {
-                Stmt::Command(cmd) => match cmd {
+                Stmt::Command(cmd, _) => match cmd {
                     Command::Action(act) => assert_eq!(act, &ActionCommand::Defend, "Body deve ser defend"),
                     _ => panic!("Body do for deve ser um comando de ação"),
                 },
@@ -139,3 +141,309 @@ fn test_multiple_statements() {
     assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
     assert_eq!(ast.len(), 3, "Deve haver 3 statements");
 }
+
+/// Extrai a expressão de um programa com um único expression statement.
+fn parse_single_expr(source: &str) -> Expr {
+    let (ast, errors) = parse_source(source);
+    assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos em '{}'", source);
+    match ast.into_iter().next() {
+        Some(Stmt::ExprStmt(expr)) => expr,
+        other => panic!("Esperado expression statement, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_multiplicative_binds_tighter_than_additive() {
+    // hero + 2 * 3 deve ser hero + (2 * 3)
+    match parse_single_expr("hero + 2 * 3") {
+        Expr::BinaryOp { left, op: BinOp::Add, right, .. } => {
+            assert!(matches!(left.as_ref(), Expr::Identifier(name, _) if name == "hero"));
+            match *right {
+                Expr::BinaryOp { op: BinOp::Mul, .. } => {}
+                other => panic!("Direita deve ser uma multiplicação, encontrado {:?}", other),
+            }
+        }
+        other => panic!("Esperado soma na raiz, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_logical_precedence() {
+    // enemy || trap && hero deve ser enemy || (trap && hero)
+    match parse_single_expr("enemy || trap && hero") {
+        Expr::BinaryOp { op: BinOp::Or, right, .. } => match *right {
+            Expr::BinaryOp { op: BinOp::And, .. } => {}
+            other => panic!("Direita deve ser um '&&', encontrado {:?}", other),
+        },
+        other => panic!("Esperado '||' na raiz, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_binary_operators_are_left_associative() {
+    // 8 / 4 / 2 deve ser (8 / 4) / 2
+    match parse_single_expr("8 / 4 / 2") {
+        Expr::BinaryOp { left, op: BinOp::Div, right, .. } => {
+            assert!(matches!(*right, Expr::Number(2, _)));
+            assert!(matches!(*left, Expr::BinaryOp { op: BinOp::Div, .. }), "Esquerda deve ser 8 / 4");
+        }
+        other => panic!("Esperado divisão na raiz, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_unary_operators() {
+    // !enemy && -hero deve ser (!enemy) && (-hero)
+    match parse_single_expr("!enemy && -hero") {
+        Expr::BinaryOp { left, op: BinOp::And, right, .. } => {
+            assert!(matches!(*left, Expr::UnaryOp { op: UnaryOp::Not, .. }), "Esquerda deve ser '!enemy'");
+            assert!(matches!(*right, Expr::UnaryOp { op: UnaryOp::Neg, .. }), "Direita deve ser '-hero'");
+        }
+        other => panic!("Esperado '&&' na raiz, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_comparison_precedence() {
+    // hero + 1 < 5 == enemy && trap deve ser (((hero + 1) < 5) == enemy) && trap
+    match parse_single_expr("hero + 1 < 5 == enemy && trap") {
+        Expr::BinaryOp { left, op: BinOp::And, .. } => match *left {
+            Expr::BinaryOp { left, op: BinOp::Eq, .. } => match *left {
+                Expr::BinaryOp { left, op: BinOp::Lt, .. } => {
+                    assert!(matches!(*left, Expr::BinaryOp { op: BinOp::Add, .. }), "Soma deve ficar dentro do '<'");
+                }
+                other => panic!("Esperado '<' dentro do '==', encontrado {:?}", other),
+            },
+            other => panic!("Esperado '==' dentro do '&&', encontrado {:?}", other),
+        },
+        other => panic!("Esperado '&&' na raiz, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_parenthesized_expressions() {
+    // (hero + 1) - (enemy - 2): os parênteses definem a forma da árvore
+    match parse_single_expr("(hero + 1) - (enemy - 2)") {
+        Expr::BinaryOp { left, op: BinOp::Sub, right, .. } => {
+            assert!(matches!(*left, Expr::BinaryOp { op: BinOp::Add, .. }), "Esquerda deve ser hero + 1");
+            assert!(matches!(*right, Expr::BinaryOp { op: BinOp::Sub, .. }), "Direita deve ser enemy - 2");
+        }
+        other => panic!("Esperado subtração na raiz, encontrado {:?}", other),
+    }
+
+    // 2 * (3 + 1): a soma agrupada fica abaixo da multiplicação
+    match parse_single_expr("2 * ((3 + 1))") {
+        Expr::BinaryOp { op: BinOp::Mul, right, .. } => {
+            assert!(matches!(*right, Expr::BinaryOp { op: BinOp::Add, .. }), "Direita deve ser 3 + 1");
+        }
+        other => panic!("Esperado multiplicação na raiz, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_unclosed_parenthesis() {
+    let (_ast, errors) = parse_source("(hero + 1");
+    assert!(errors.has_errors(), "Parêntese não fechado deve gerar erro sintático");
+}
+
+#[test]
+fn test_let_and_assign_statements() {
+    let (ast, errors) = parse_source("let steps: int = 3\nlet alert: bool = enemy\nsteps = steps - 1");
+    assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
+    assert_eq!(ast.len(), 3, "Deve haver 3 statements");
+    match &ast[0] {
+        Stmt::LetStmt { name, var_type, value, .. } => {
+            assert_eq!(name, "steps");
+            assert_eq!(var_type, &SymbolType::Integer);
+            assert!(matches!(value, Expr::Number(3, _)));
+        }
+        other => panic!("Esperado LetStmt, encontrado {:?}", other),
+    }
+    assert!(matches!(&ast[1], Stmt::LetStmt { var_type: SymbolType::Boolean, .. }), "Segundo let deve ser bool");
+    match &ast[2] {
+        Stmt::AssignStmt { name, value, .. } => {
+            assert_eq!(name, "steps");
+            assert!(matches!(value, Expr::BinaryOp { op: BinOp::Sub, .. }), "Valor deve ser steps - 1");
+        }
+        other => panic!("Esperado AssignStmt, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_for_with_counter() {
+    let (ast, errors) = parse_source("for (let i: int = 0; i < 3; i = i + 1) { move_up }");
+    assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
+    match &ast[0] {
+        Stmt::ForStmt { init, update, .. } => {
+            assert!(matches!(init.as_ref(), Stmt::LetStmt { .. }), "Init deve ser uma declaração");
+            assert!(matches!(update.as_ref(), Stmt::AssignStmt { .. }), "Update deve ser uma atribuição");
+        }
+        other => panic!("Esperado ForStmt, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_let_requires_type() {
+    let (_ast, errors) = parse_source("let steps = 3");
+    assert!(errors.has_errors(), "Declaração sem tipo deve gerar erro sintático");
+    let (_ast, errors) = parse_source("let steps: string = 3");
+    assert!(errors.has_errors(), "Tipo desconhecido deve gerar erro sintático");
+}
+
+#[test]
+fn test_if_without_else() {
+    let (ast, errors) = parse_source("if (enemy) { attack }\nmove_up");
+    assert!(!errors.has_errors(), "O else deve ser opcional");
+    assert_eq!(ast.len(), 2, "Deve haver 2 statements");
+    match &ast[0] {
+        Stmt::IfStmt { else_branch, .. } => assert!(else_branch.is_empty(), "Sem else, o ramo deve ficar vazio"),
+        other => panic!("Esperado IfStmt, encontrado {:?}", other),
+    }
+}
+
+#[test]
+fn test_else_on_next_line() {
+    let (ast, errors) = parse_source("if (enemy) {\n  attack\n}\nelse {\n  defend\n}");
+    assert!(!errors.has_errors(), "O else pode começar na linha seguinte ao '}}'");
+    assert_eq!(ast.len(), 1, "O else deve pertencer ao if");
+}
+
+#[test]
+fn test_else_if_chain() {
+    let source = "if (enemy) { attack } else if (trap) { jump } else if (treasure > 0) { move_up } else { defend }";
+    let (ast, errors) = parse_source(source);
+    assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
+    assert_eq!(ast.len(), 1, "A cadeia inteira é um único statement");
+
+    // Cada `else if` é um IfStmt sozinho dentro do else_branch anterior.
+    let mut depth = 0;
+    let mut current = &ast[0];
+    loop {
+        match current {
+            Stmt::IfStmt { else_branch, .. } => match else_branch.as_slice() {
+                [next @ Stmt::IfStmt { .. }] => {
+                    depth += 1;
+                    current = next;
+                }
+                [Stmt::Command(Command::Action(ActionCommand::Defend), _)] => break,
+                other => panic!("Else final inesperado: {:?}", other),
+            },
+            other => panic!("Esperado IfStmt, encontrado {:?}", other),
+        }
+    }
+    assert_eq!(depth, 2, "Devem existir dois 'else if' encadeados");
+}
+
+#[test]
+fn test_nodes_carry_source_spans() {
+    let source = "move_up\nif (hero > 1) {\n  let steps: int = (hero + 1) * 2\n}";
+    let tokens = Lexer::new(source).tokenize();
+    let (ast, errors) = Parser::new(tokens, source).parse();
+    assert!(!errors.has_errors());
+
+    let text = |span: Span| &source[span.start..span.end];
+    assert_eq!(text(ast[0].span()), "move_up");
+    assert_eq!(text(ast[1].span()), "if (hero > 1) {\n  let steps: int = (hero + 1) * 2\n}", "O if deve ir até o '}}'");
+    match &ast[1] {
+        Stmt::IfStmt { condition, then_branch, .. } => {
+            assert_eq!(text(condition.span()), "hero > 1");
+            assert_eq!(text(then_branch[0].span()), "let steps: int = (hero + 1) * 2");
+            match &then_branch[0] {
+                Stmt::LetStmt { value, .. } => assert_eq!(text(value.span()), "(hero + 1) * 2", "Parênteses fazem parte do operando esquerdo"),
+                _ => panic!("Esperado LetStmt"),
+            }
+        }
+        _ => panic!("Esperado IfStmt"),
+    }
+}
+
+#[test]
+fn test_span_line_col_range() {
+    let source = "move_up\nif (hero) {\n  jump\n}";
+    let tokens = Lexer::new(source).tokenize();
+    let (ast, _) = Parser::new(tokens, source).parse();
+    assert_eq!(ast[0].span().line_col_range(source), ((1, 1), (1, 8)));
+    assert_eq!(ast[1].span().line_col_range(source), ((2, 1), (4, 2)), "O if ocupa da linha 2 até o '}}' na linha 4");
+}
+
+#[test]
+fn test_syntax_errors_carry_codes() {
+    let cases = [
+        ("if (hero { jump }", "Q0102"),
+        ("while enemy) { defend }", "Q0101"),
+        ("while (enemy) defend", "Q0103"),
+        ("let steps int = 3", "Q0109"),
+        ("let steps: float = 3", "Q0110"),
+        ("hero + ", "Q0106"),
+    ];
+    for (source, expected) in cases {
+        let (_, errors) = parse_source(source);
+        let first = errors.errors().first().expect("Deve haver erro sintático");
+        assert_eq!(first.code.map(|code| code.code()), Some(expected), "Código incorreto para '{}'", source);
+    }
+}
+
+#[test]
+fn test_lexical_errors_do_not_become_syntax_errors() {
+    let (ast, errors) = parse_source("move_up @ attack\n$ jump");
+    let types: Vec<ErrorType> = errors.errors().iter().map(|error| error.error_type.clone()).collect();
+    assert_eq!(types, [ErrorType::Lexical, ErrorType::Lexical], "Cada token inválido gera só o erro léxico");
+    assert_eq!(ast.len(), 3, "Os comandos válidos devem ser mantidos");
+}
+
+#[test]
+fn test_parser_reports_lexical_errors() {
+    // Sem juntar os erros do lexer, o parser sozinho já acusa o literal inválido.
+    let (_, errors) = parse_source("let x: int = 123abc");
+    let codes: Vec<_> = errors.errors().iter().filter_map(|error| error.code.map(|code| code.code())).collect();
+    assert_eq!(codes, ["Q0002"], "O número malformado deve ser reportado uma única vez");
+}
+
+#[test]
+fn test_missing_paren_does_not_cascade() {
+    let (ast, errors) = parse_source("if (hero { move_left } else { move_right }\nattack");
+    assert_eq!(errors.errors().len(), 1, "Um ')' faltando deve gerar um único erro");
+    match &ast[0] {
+        Stmt::IfStmt { then_branch, else_branch, .. } => {
+            assert_eq!(then_branch.len(), 1);
+            assert_eq!(else_branch.len(), 1, "O if deve ser recuperado inteiro");
+        }
+        other => panic!("Esperado IfStmt, obtido {:?}", other),
+    }
+    assert!(matches!(ast[1], Stmt::Command(Command::Action(ActionCommand::Attack), _)));
+}
+
+#[test]
+fn test_recovery_inserts_error_nodes() {
+    let source = "let x: int = ;\nmove_up";
+    let (ast, errors) = parse_source(source);
+    assert!(errors.has_errors());
+    assert_eq!(ast.len(), 2, "O statement seguinte ao erro deve ser analisado");
+    match &ast[0] {
+        Stmt::Error(span) => assert_eq!(&source[span.start..span.end], "let x: int = ;", "O nó de erro cobre o trecho descartado"),
+        other => panic!("Esperado Stmt::Error, obtido {:?}", other),
+    }
+    assert!(matches!(ast[1], Stmt::Command(Command::Move(MoveCommand::MoveUp), _)));
+}
+
+#[test]
+fn test_invalid_condition_becomes_error_expression() {
+    let (ast, errors) = parse_source("while (+) { jump }");
+    assert_eq!(errors.errors().len(), 1);
+    match &ast[0] {
+        Stmt::WhileStmt { condition, body, .. } => {
+            assert!(matches!(condition, Expr::Error(_)), "A condição inválida vira Expr::Error");
+            assert_eq!(body.len(), 1, "O corpo continua sendo analisado");
+        }
+        other => panic!("Esperado WhileStmt, obtido {:?}", other),
+    }
+}
+
+#[test]
+fn test_duplicate_errors_are_suppressed() {
+    let (_, errors) = parse_source("for (hero; enemy treasure) { defend }");
+    let mut positions: Vec<(usize, usize)> = errors.errors().iter().map(|e| (e.line, e.column)).collect();
+    let total = positions.len();
+    positions.dedup();
+    assert_eq!(positions.len(), total, "Não deve haver dois erros na mesma posição");
+}
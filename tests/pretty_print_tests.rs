@@ -1,20 +1,24 @@
-use questlang::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr, BinOp};
+use questlang::parser::ast::{Stmt, Command, MoveCommand, ActionCommand, Expr, BinOp, UnaryOp, Span};
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::parser::Parser;
 use questlang::pretty_print::PrettyPrinter;
 
 fn build_sample_ast() -> Vec<Stmt> {
     vec![
         // Statement 1: move_up
-        Stmt::Command(Command::Move(MoveCommand::MoveUp)),
+        Stmt::Command(Command::Move(MoveCommand::MoveUp), Span::default()),
         // Statement 2: if (hero) { move_left } else { move_right }
         Stmt::IfStmt {
-            condition: Expr::Identifier("hero".to_string()),
-            then_branch: vec![Stmt::Command(Command::Move(MoveCommand::MoveLeft))],
-            else_branch: vec![Stmt::Command(Command::Move(MoveCommand::MoveRight))],
+            condition: Expr::Identifier("hero".to_string(), Span::default()),
+            then_branch: vec![Stmt::Command(Command::Move(MoveCommand::MoveLeft), Span::default())],
+            else_branch: vec![Stmt::Command(Command::Move(MoveCommand::MoveRight), Span::default())],
+            span: Span::default(),
         },
         // Statement 3: while (enemy) { jump }
         Stmt::WhileStmt {
-            condition: Expr::Identifier("enemy".to_string()),
-            body: vec![Stmt::Command(Command::Action(ActionCommand::Jump))],
+            condition: Expr::Identifier("enemy".to_string(), Span::default()),
+            body: vec![Stmt::Command(Command::Action(ActionCommand::Jump), Span::default())],
+            span: Span::default(),
         },
     ]
 }
@@ -37,3 +41,95 @@ while (enemy) {
 ";
     assert_eq!(output, expected, "O pretty print não corresponde ao esperado");
 }
+
+#[test]
+fn test_pretty_print_operators() {
+    // hero * 2 || !enemy
+    let ast = vec![Stmt::ExprStmt(Expr::BinaryOp {
+        left: Box::new(Expr::BinaryOp {
+            left: Box::new(Expr::Identifier("hero".to_string(), Span::default())),
+            op: BinOp::Mul,
+            op_span: Span::default(),
+            right: Box::new(Expr::Number(2, Span::default())),
+            span: Span::default(),
+        }),
+        op: BinOp::Or,
+        op_span: Span::default(),
+        right: Box::new(Expr::UnaryOp {
+            op: UnaryOp::Not,
+            operand: Box::new(Expr::Identifier("enemy".to_string(), Span::default())),
+            span: Span::default(),
+        }),
+        span: Span::default(),
+    })];
+    let mut printer = PrettyPrinter::new();
+    assert_eq!(printer.print_stmts(&ast), "Expr: hero * 2 || !enemy\n");
+}
+
+#[test]
+fn test_pretty_print_only_needed_parentheses() {
+    let cases = [
+        ("(hero + 1) - (enemy - 2)", "Expr: hero + 1 - (enemy - 2)\n"),
+        ("((hero * 2))", "Expr: hero * 2\n"),
+        ("2 * (3 + hero)", "Expr: 2 * (3 + hero)\n"),
+        ("-(hero + 1) < 3", "Expr: -(hero + 1) < 3\n"),
+        ("!(enemy && trap) || (trap)", "Expr: !(enemy && trap) || trap\n"),
+    ];
+    for (source, expected) in cases {
+        let tokens = Lexer::new(source).tokenize();
+        let (ast, errors) = Parser::new(tokens, source).parse();
+        assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos em '{}'", source);
+        let mut printer = PrettyPrinter::new();
+        assert_eq!(printer.print_stmts(&ast), expected, "Parênteses incorretos para '{}'", source);
+    }
+}
+
+#[test]
+fn test_pretty_print_declarations() {
+    let source = "let steps: int = 0\nfor (let i: int = 0; i < 3; i = i + 1) { steps = steps + i }";
+    let tokens = Lexer::new(source).tokenize();
+    let (ast, errors) = Parser::new(tokens, source).parse();
+    assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
+    let mut printer = PrettyPrinter::new();
+    let expected = "\
+let steps: int = 0
+for (let i: int = 0; i < 3; i = i + 1) {
+  steps = steps + i
+}
+";
+    assert_eq!(printer.print_stmts(&ast), expected);
+}
+
+#[test]
+fn test_pretty_print_for_header_with_any_statement() {
+    // Uma AST montada à mão (ou lida de JSON) pode ter qualquer statement no cabeçalho.
+    let ast = vec![Stmt::ForStmt {
+        init: Box::new(Stmt::Command(Command::Action(ActionCommand::Jump), Span::default())),
+        condition: Expr::Identifier("enemy".to_string(), Span::default()),
+        update: Box::new(Stmt::Error(Span::default())),
+        body: vec![],
+        span: Span::default(),
+    }];
+    let mut printer = PrettyPrinter::new();
+    assert_eq!(printer.print_stmts(&ast), "for (jump; enemy; <error>) {\n}\n", "O cabeçalho não deve cair no formato de Debug");
+}
+
+#[test]
+fn test_pretty_print_else_if_without_empty_else() {
+    let source = "if (enemy) { attack } else if (trap) { jump }\nif (hero) { defend }";
+    let tokens = Lexer::new(source).tokenize();
+    let (ast, errors) = Parser::new(tokens, source).parse();
+    assert!(!errors.has_errors(), "Não devem ocorrer erros sintáticos");
+    let mut printer = PrettyPrinter::new();
+    let expected = "\
+if (enemy) {
+  Command: attack
+} else if (trap) {
+  Command: jump
+}
+if (hero) {
+  Command: defend
+}
+";
+    assert_eq!(printer.print_stmts(&ast), expected, "Else vazio não deve ser impresso");
+}
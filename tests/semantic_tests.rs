@@ -0,0 +1,175 @@
+use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::Span;
+use questlang::parser::ast_builder::ASTBuilder;
+use questlang::parser::parser::Parser;
+use questlang::semantic::analyzer::SemanticAnalyzer;
+use questlang::symbol_table::symbol_table::SymbolTable;
+use questlang::symbol_table::symbol_type::SymbolType;
+
+/// Função auxiliar que faz o parse e a análise semântica da fonte.
+fn analyze(source: &str) -> (SymbolTable, ErrorHandler) {
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize();
+    let (ast, errors) = Parser::new(tokens, source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido");
+    SemanticAnalyzer::new(source).analyze(&ast)
+}
+
+#[test]
+fn test_builtins_are_resolved() {
+    let (table, errors) = analyze("if (hero) { jump } else { attack }\nwhile (enemy) { defend }");
+    assert!(!errors.has_errors(), "Símbolos pré-definidos não devem gerar erros");
+
+    let hero = table.lookup("hero").expect("'hero' deve estar na tabela");
+    assert_eq!(hero.symbol_type, SymbolType::Integer);
+    let enemy = table.lookup("enemy").expect("'enemy' deve estar na tabela");
+    assert_eq!(enemy.symbol_type, SymbolType::Boolean);
+}
+
+#[test]
+fn test_symbol_position_is_first_use() {
+    let source = "move_up\n  while (treasure) { jump }\nif (treasure) { jump } else { jump }";
+    let (table, _) = analyze(source);
+    let treasure = table.lookup("treasure").unwrap();
+    assert_eq!((treasure.line, treasure.column), (2, 10), "Posição deve ser a do primeiro uso");
+}
+
+#[test]
+fn test_comments_and_longer_identifiers_are_ignored() {
+    let source = "// hero enemy trap\nheroes + 1";
+    let (table, errors) = analyze(source);
+    assert!(table.lookup("hero").is_none(), "'hero' só aparece em comentário");
+    assert!(table.lookup("trap").is_none(), "'trap' só aparece em comentário");
+    assert!(errors.has_errors(), "'heroes' não é um símbolo definido");
+}
+
+#[test]
+fn test_undefined_identifier_reports_each_use() {
+    let source = "dragon + 1\nif (hero) { jump } else { dragon - 2 }";
+    let (_, errors) = analyze(source);
+    assert!(errors.has_errors(), "Identificador indefinido deve gerar erro semântico");
+    let report: Vec<String> = errors.errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(
+        report,
+        vec![
+            "[Semantic Error] Undefined identifier 'dragon' (Linha 1, Coluna 1)",
+            "[Semantic Error] Undefined identifier 'dragon' (Linha 2, Coluna 27)",
+        ]
+    );
+}
+
+#[test]
+fn test_empty_program() {
+    let (table, errors) = analyze("");
+    assert!(!errors.has_errors());
+    assert!(table.symbols.is_empty(), "Nenhum símbolo usado, tabela vazia");
+}
+
+fn messages(errors: &ErrorHandler) -> Vec<String> {
+    errors.errors().iter().map(|e| e.to_string()).collect()
+}
+
+#[test]
+fn test_declarations_are_scoped() {
+    let source = "let steps: int = 2\nwhile (steps > 0) {\n  let bonus: int = 1\n  steps = steps - bonus\n}\nbonus + 1";
+    let (table, errors) = analyze(source);
+    let steps = table.lookup("steps").expect("'steps' é global e deve continuar na tabela");
+    assert_eq!((steps.line, steps.column, steps.scope_level), (1, 1, 0), "Posição deve ser a da declaração");
+    assert!(table.lookup("bonus").is_none(), "'bonus' deve sair da tabela junto com o bloco");
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Undefined identifier 'bonus' (Linha 6, Coluna 1)"],
+        "'bonus' não é visível fora do bloco"
+    );
+}
+
+#[test]
+fn test_builtins_stay_global_when_first_used_in_a_block() {
+    let (table, errors) = analyze("if (enemy) { hero - 1 } else { jump }\nhero + 1");
+    assert!(!errors.has_errors());
+    let hero = table.lookup("hero").expect("'hero' deve continuar na tabela após o bloco");
+    assert_eq!((hero.line, hero.column), (1, 14), "Posição deve ser a do primeiro uso, dentro do bloco");
+}
+
+#[test]
+fn test_redeclaration_and_invalid_assignments() {
+    let source = "let steps: int = 1\nlet steps: int = 2\nlet hero: int = 3\nlost = 4\ntrap = enemy";
+    let (_, errors) = analyze(source);
+    assert_eq!(
+        messages(&errors),
+        vec![
+            "[Semantic Error] Variable 'steps' was already declared on line 1 (Linha 2, Coluna 5)",
+            "[Semantic Error] Cannot redeclare predefined symbol 'hero' (Linha 3, Coluna 5)",
+            "[Semantic Error] Assignment to undeclared variable 'lost' (Linha 4, Coluna 1)",
+            "[Semantic Error] Cannot assign to predefined symbol 'trap' (Linha 5, Coluna 1)",
+        ]
+    );
+}
+
+#[test]
+fn test_declaration_errors_point_at_the_name() {
+    let source = "let steps: int = 1\nif (hero) { let steps: int = 2\n let steps: int = 3 }\nlost = steps + 1";
+    let (_, errors) = analyze(source);
+    let underlined: Vec<&str> = errors
+        .errors()
+        .iter()
+        .map(|error| error.span.map_or("", |span| &source[span.start..span.end]))
+        .collect();
+    assert_eq!(underlined, ["steps", "lost"], "O erro deve sublinhar só o nome, não o statement inteiro");
+}
+
+#[test]
+fn test_declaration_errors_use_the_recorded_name_span() {
+    // AST montada à mão: o span do nome vem do nó, não de uma nova leitura da fonte.
+    let source = "let hero: int = 1";
+    let builder = ASTBuilder::new();
+    let value = builder.new_number(1, Span::new(16, 17));
+    let ast = vec![builder.new_let("hero", Span::new(4, 8), SymbolType::Integer, value, Span::new(0, 17))];
+    let (_, errors) = SemanticAnalyzer::new(source).analyze(&ast);
+    let error = errors.errors().first().expect("Redeclarar 'hero' deve ser um erro");
+    assert_eq!(error.span, Some(Span::new(4, 8)), "O erro deve usar o span do nome guardado no nó");
+    assert_eq!((error.line, error.column), (1, 5), "A posição deve ser a do nome");
+}
+
+#[test]
+fn test_for_variable_is_visible_in_header_and_body() {
+    let (table, errors) = analyze("for (let i: int = 0; i < 3; i = i + 1) { i - 1 }\ni");
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Undefined identifier 'i' (Linha 2, Coluna 1)"],
+        "'i' só existe dentro do for"
+    );
+    assert!(table.lookup("i").is_none());
+}
+
+#[test]
+fn test_inner_scope_can_shadow_outer_variable() {
+    // Mesma regra do interpretador e da VM: um bloco interno pode declarar o nome de novo.
+    let source = "let n: int = 3\nfor (let i: int = 0; i < n; i = i + 1) { let n: bool = enemy\n if (n) { jump } }\nn = n + 1";
+    let (table, errors) = analyze(source);
+    assert!(!errors.has_errors(), "Sombrear uma variável de fora não é erro: {:?}", messages(&errors));
+    let n = table.lookup("n").expect("'n' de fora deve voltar a valer depois do for");
+    assert_eq!((n.symbol_type.clone(), n.line), (SymbolType::Integer, 1));
+
+    let (_, errors) = analyze("if (enemy) { let n: int = 1\n let n: int = 2 }");
+    assert_eq!(errors.errors().len(), 1, "Redeclarar no mesmo escopo continua sendo erro");
+}
+
+#[test]
+fn test_semantic_errors_carry_codes() {
+    let source = "let steps: int = 1\nlet steps: int = 2\nlet hero: int = 3\nlost = 4\ntrap = enemy\ndragon";
+    let (_, errors) = analyze(source);
+    let codes: Vec<String> = errors.errors().iter().map(|e| e.code.map_or(String::new(), |c| c.to_string())).collect();
+    assert_eq!(codes, vec!["Q0203", "Q0202", "Q0204", "Q0205", "Q0201"]);
+}
+
+#[test]
+fn test_partial_ast_is_still_analyzed() {
+    let source = "if (hero { jump }\nwhile (+) { defend }\ndragon";
+    let (ast, syntax_errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+    assert!(syntax_errors.has_errors());
+    let (_, errors) = SemanticAnalyzer::new(source).analyze(&ast);
+    let codes: Vec<String> = errors.errors().iter().map(|e| e.code.map_or(String::new(), |c| c.to_string())).collect();
+    assert_eq!(codes, vec!["Q0201"], "Nós de erro são ignorados, o resto da árvore é analisado");
+}
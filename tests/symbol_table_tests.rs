@@ -102,6 +102,21 @@ fn test_scope_decrease_does_not_remove_global_symbols() {
     assert!(table.lookup("hero").is_some());
 }
 
+#[test]
+fn test_inner_scope_shadows_and_restores() {
+    let mut table = SymbolTable::new();
+    table.insert("n", SymbolType::Integer, 1, 1);
+
+    table.enter_scope();
+    assert!(table.lookup_current("n").is_none(), "'n' não foi declarado no escopo interno");
+    table.insert("n", SymbolType::Boolean, 2, 2);
+    assert_eq!(table.lookup("n").unwrap().symbol_type, SymbolType::Boolean, "O 'n' interno deve esconder o de fora");
+
+    table.exit_scope();
+    let n = table.lookup("n").unwrap();
+    assert_eq!((n.symbol_type.clone(), n.line), (SymbolType::Integer, 1), "O 'n' de fora deve voltar ao sair do escopo");
+}
+
 /// Teste opcional: captura da saída do print_table para garantir que não haja panics.
 /// Para esse teste, apenas chamamos print_table e verificamos que não há panics.
 #[test]
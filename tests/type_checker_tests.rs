@@ -0,0 +1,167 @@
+use questlang::error_handler::error_handler::ErrorHandler;
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::{BinOp, Span};
+use questlang::parser::ast_builder::ASTBuilder;
+use questlang::parser::parser::Parser;
+use questlang::semantic::type_checker::{ConditionRule, TypeChecker};
+
+/// Função auxiliar que faz o parse da fonte e roda o type checker com a regra dada.
+fn check(source: &str, rule: ConditionRule) -> ErrorHandler {
+    let mut lexer = Lexer::new(source);
+    let tokens = lexer.tokenize();
+    let (ast, errors) = Parser::new(tokens, source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido");
+    TypeChecker::new(source).with_condition_rule(rule).check(&ast)
+}
+
+fn messages(errors: &ErrorHandler) -> Vec<String> {
+    errors.errors().iter().map(|e| e.to_string()).collect()
+}
+
+#[test]
+fn test_integer_arithmetic_is_valid() {
+    let errors = check("if (hero + 10 - 5) { jump } else { attack }\ntreasure - 1", ConditionRule::Truthiness);
+    assert!(!errors.has_errors(), "Aritmética entre inteiros é válida");
+}
+
+#[test]
+fn test_boolean_arithmetic_is_rejected() {
+    let errors = check("while (enemy - 3) { jump }", ConditionRule::Truthiness);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Operator '-' expects Integer operands, found Boolean (Linha 1, Coluna 14)"],
+        "Subtrair de um booleano deve apontar para o operador"
+    );
+}
+
+#[test]
+fn test_boolean_on_the_right_is_rejected() {
+    let errors = check("move_up\nhero + 2 - trap", ConditionRule::Truthiness);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Operator '-' expects Integer operands, found Boolean (Linha 2, Coluna 10)"]
+    );
+}
+
+#[test]
+fn test_truthiness_rule_accepts_integer_conditions() {
+    let errors = check("if (hero) { jump } else { attack }\nwhile (treasure) { move_up }", ConditionRule::Truthiness);
+    assert!(!errors.has_errors(), "Com a regra de truthiness, inteiros valem como condição");
+}
+
+#[test]
+fn test_boolean_only_rule_rejects_integer_conditions() {
+    let source = "if (enemy) { jump } else { attack }\nfor (hero; hero - 1; trap) { defend }";
+    let errors = check(source, ConditionRule::BooleanOnly);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Condition of 'for' must be Boolean, found Integer (Linha 2, Coluna 12)"],
+        "Só a condição inteira do for deve ser rejeitada"
+    );
+}
+
+#[test]
+fn test_unknown_identifiers_are_left_to_the_analyzer() {
+    let errors = check("dragon + 1", ConditionRule::BooleanOnly);
+    assert!(!errors.has_errors(), "Identificadores desconhecidos não têm tipo e não geram erro de tipo");
+}
+
+#[test]
+fn test_logical_operators_expect_booleans() {
+    let errors = check("enemy && trap || !enemy", ConditionRule::BooleanOnly);
+    assert!(!errors.has_errors(), "Operadores lógicos entre booleanos são válidos");
+
+    let errors = check("enemy && hero * 2", ConditionRule::BooleanOnly);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Operator '&&' expects Boolean operands, found Integer (Linha 1, Coluna 7)"]
+    );
+}
+
+#[test]
+fn test_unary_operators() {
+    let errors = check("!hero", ConditionRule::Truthiness);
+    assert!(!errors.has_errors(), "Com truthiness, '!' aceita inteiros");
+
+    let errors = check("hero + -trap", ConditionRule::Truthiness);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Operator '-' expects a Integer operand, found Boolean (Linha 1, Coluna 8)"]
+    );
+}
+
+#[test]
+fn test_comparisons_produce_booleans() {
+    let source = "while (treasure > 0 && hero <= 10) { jump }\nif (enemy != trap) { attack } else { defend }";
+    let errors = check(source, ConditionRule::BooleanOnly);
+    assert!(!errors.has_errors(), "Comparações resultam em Boolean");
+}
+
+#[test]
+fn test_comparison_operand_types() {
+    let errors = check("enemy < 3", ConditionRule::Truthiness);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Operator '<' expects Integer operands, found Boolean (Linha 1, Coluna 7)"]
+    );
+
+    let errors = check("hero == trap", ConditionRule::Truthiness);
+    assert_eq!(
+        messages(&errors),
+        vec!["[Semantic Error] Operator '==' expects operands of the same type, found Integer and Boolean (Linha 1, Coluna 6)"]
+    );
+}
+
+#[test]
+fn test_declarations_and_assignments_keep_their_type() {
+    let source = "let alert: bool = enemy && trap\nwhile (alert) { defend\nalert = !alert }";
+    let errors = check(source, ConditionRule::BooleanOnly);
+    assert!(!errors.has_errors(), "Variável booleana vale como condição");
+
+    let source = "let steps: int = enemy\nsteps = steps > 2";
+    let errors = check(source, ConditionRule::Truthiness);
+    assert_eq!(
+        messages(&errors),
+        vec![
+            "[Semantic Error] Variable 'steps' has type Integer, found Boolean (Linha 1, Coluna 18)",
+            "[Semantic Error] Variable 'steps' has type Integer, found Boolean (Linha 2, Coluna 9)",
+        ],
+        "Tipos incompatíveis devem ser rejeitados mesmo com truthiness"
+    );
+}
+
+#[test]
+fn test_errors_carry_the_span_of_the_offending_code() {
+    let source = "let alert: bool = hero <= 3\nalert = hero";
+    let errors = check(source, ConditionRule::Truthiness);
+    let spans: Vec<&str> = errors
+        .errors()
+        .iter()
+        .map(|e| e.span.map_or("", |span| &source[span.start..span.end]))
+        .collect();
+    assert_eq!(spans, vec!["hero"], "O erro deve apontar para o valor atribuído");
+
+    let errors = check("(enemy) <= (trap)", ConditionRule::Truthiness);
+    let span = errors.errors()[0].span.expect("Erro de operador deve ter span");
+    assert_eq!(&"(enemy) <= (trap)"[span.start..span.end], "<=", "O erro deve apontar para o operador");
+}
+
+#[test]
+fn test_operator_errors_use_the_recorded_operator_span() {
+    // Operandos sem posição, como numa AST montada à mão: só o nó binário sabe onde está o operador.
+    let source = "hero + enemy";
+    let builder = ASTBuilder::new();
+    let left = builder.new_identifier("hero", Span::default());
+    let right = builder.new_identifier("enemy", Span::default());
+    let ast = vec![builder.new_expr_stmt(builder.new_binop(left, BinOp::Add, Span::new(5, 6), right, Span::new(0, 12)))];
+    let errors = TypeChecker::new(source).check(&ast);
+    assert_eq!(errors.errors()[0].span, Some(Span::new(5, 6)), "O erro deve usar o span do operador guardado no nó");
+}
+
+#[test]
+fn test_shadowing_variable_has_its_own_type() {
+    let source = "let n: int = 3\nif (enemy) { let n: bool = trap\n n = 1 }\nn = n + 1";
+    let errors = check(source, ConditionRule::Truthiness);
+    assert_eq!(errors.errors().len(), 1, "Só a atribuição ao 'n' booleano deve falhar: {:?}", messages(&errors));
+    assert_eq!(errors.errors()[0].line, 3);
+}
@@ -0,0 +1,150 @@
+use questlang::lexer::lexer::Lexer;
+use questlang::parser::ast::{Command, Expr, Span, Stmt, UnaryOp};
+use questlang::parser::parser::Parser;
+use questlang::parser::visitor::{fold_expr, walk_expr, walk_expr_mut, walk_stmt, walk_stmt_mut, Fold, Visitor, VisitorMut};
+use questlang::pretty_print::PrettyPrinter;
+
+fn parse(source: &str) -> Vec<Stmt> {
+    let (ast, errors) = Parser::new(Lexer::new(source).tokenize(), source).parse();
+    assert!(!errors.has_errors(), "O programa de teste deve ser sintaticamente válido:\n{}", source);
+    ast
+}
+
+/// Anota nomes e comandos na ordem em que são visitados.
+#[derive(Default)]
+struct Collector {
+    names: Vec<String>,
+    commands: Vec<String>,
+}
+
+impl Visitor for Collector {
+    fn visit_expr(&mut self, expr: &Expr) {
+        if let Expr::Identifier(name, _) = expr {
+            self.names.push(name.clone());
+        }
+        walk_expr(self, expr);
+    }
+
+    fn visit_command(&mut self, command: &Command, _span: Span) {
+        self.commands.push(command.to_string());
+    }
+}
+
+#[test]
+fn test_visitor_reaches_every_node() {
+    let ast = parse(
+        "if (hero > 1 && !enemy) { jump } else if (trap) { attack } else { defend }\n\
+         while (treasure) { move_up }\n\
+         for (let i: int = a; i < b; i = i + c) { move_left }\n\
+         let x: int = -d\n\
+         x = e",
+    );
+    let mut collector = Collector::default();
+    collector.visit_stmts(&ast);
+    assert_eq!(
+        collector.names,
+        ["hero", "enemy", "trap", "treasure", "a", "i", "b", "i", "c", "d", "e"],
+        "Todos os identificadores devem ser visitados, na ordem de execução"
+    );
+    assert_eq!(collector.commands, ["jump", "attack", "defend", "move_up", "move_left"]);
+}
+
+#[test]
+fn test_visitor_can_skip_children() {
+    // Sem chamar `walk_expr`, só as expressões de topo são visitadas.
+    struct TopLevel(usize);
+    impl Visitor for TopLevel {
+        fn visit_expr(&mut self, _expr: &Expr) {
+            self.0 += 1;
+        }
+    }
+    let ast = parse("if (a && (b || c)) { jump }\nlet x: int = 1 + 2 * 3");
+    let mut visitor = TopLevel(0);
+    visitor.visit_stmts(&ast);
+    assert_eq!(visitor.0, 2, "Os filhos não devem ser visitados sem walk_expr");
+}
+
+#[test]
+fn test_visitor_mut_renames_variable() {
+    struct Rename;
+    impl VisitorMut for Rename {
+        fn visit_stmt_mut(&mut self, stmt: &mut Stmt) {
+            if let Stmt::LetStmt { name, .. } | Stmt::AssignStmt { name, .. } = stmt {
+                if name == "i" {
+                    *name = "step".to_string();
+                }
+            }
+            walk_stmt_mut(self, stmt);
+        }
+
+        fn visit_expr_mut(&mut self, expr: &mut Expr) {
+            if let Expr::Identifier(name, _) = expr {
+                if name == "i" {
+                    *name = "step".to_string();
+                }
+            }
+            walk_expr_mut(self, expr);
+        }
+    }
+    let mut ast = parse("for (let i: int = 0; i < 3; i = i + 1) { if (i == 2) { jump } }");
+    Rename.visit_stmts_mut(&mut ast);
+    let output = PrettyPrinter::new().print_stmts(&ast);
+    assert_eq!(
+        output,
+        "for (let step: int = 0; step < 3; step = step + 1) {\n  if (step == 2) {\n    Command: jump\n  }\n}\n",
+        "Todas as ocorrências de 'i' devem ser renomeadas"
+    );
+}
+
+#[test]
+fn test_fold_rebuilds_tree() {
+    // Troca `-n` por um número negativo; o resto da árvore é refeito pelo padrão.
+    struct NegateLiterals;
+    impl Fold for NegateLiterals {
+        fn fold_expr(&mut self, expr: Expr) -> Expr {
+            match fold_expr(self, expr) {
+                Expr::UnaryOp { op: UnaryOp::Neg, operand, span } => match *operand {
+                    Expr::Number(n, _) => Expr::Number(-n, span),
+                    operand => Expr::UnaryOp { op: UnaryOp::Neg, operand: Box::new(operand), span },
+                },
+                expr => expr,
+            }
+        }
+    }
+    let ast = NegateLiterals.fold_stmts(parse("while (hero > -3) { let x: int = -(-2) }"));
+    let output = PrettyPrinter::new().print_stmts(&ast);
+    assert_eq!(output, "while (hero > -3) {\n  let x: int = 2\n}\n");
+    let Stmt::WhileStmt { condition: Expr::BinaryOp { right, .. }, .. } = &ast[0] else {
+        panic!("Esperava um while com condição binária");
+    };
+    assert!(matches!(**right, Expr::Number(-3, _)), "'-3' deve virar um literal");
+}
+
+#[test]
+fn test_fold_span_reaches_every_span() {
+    struct Shift;
+    impl Fold for Shift {
+        fn fold_span(&mut self, span: Span) -> Span {
+            Span::new(span.start + 100, span.end + 100)
+        }
+    }
+    struct Spans(Vec<Span>);
+    impl Visitor for Spans {
+        fn visit_stmt(&mut self, stmt: &Stmt) {
+            self.0.push(stmt.span());
+            walk_stmt(self, stmt);
+        }
+        fn visit_expr(&mut self, expr: &Expr) {
+            self.0.push(expr.span());
+            walk_expr(self, expr);
+        }
+    }
+
+    let ast = parse("for (let i: int = 0; i < 3; i = i + 1) { if (!trap) { jump } }\nhero + 1");
+    let mut before = Spans(Vec::new());
+    before.visit_stmts(&ast);
+    let mut after = Spans(Vec::new());
+    after.visit_stmts(&Shift.fold_stmts(ast));
+    let shifted: Vec<Span> = before.0.iter().map(|span| Span::new(span.start + 100, span.end + 100)).collect();
+    assert_eq!(after.0, shifted, "Todos os spans devem passar por fold_span");
+}